@@ -0,0 +1,24 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use svc_std::password_hasher::argon2::Argon2PasswordHasher;
+
+fn hash(c: &mut Criterion) {
+    let hasher = Argon2PasswordHasher::default();
+    c.bench_function("argon2 hash", |b| {
+        b.iter(|| hasher.hash_password("mmholAhsbC123*").unwrap())
+    });
+}
+
+fn verify(c: &mut Criterion) {
+    let hasher = Argon2PasswordHasher::default();
+    let hash = hasher.hash_password("mmholAhsbC123*").unwrap();
+    c.bench_function("argon2 verify", |b| {
+        b.iter(|| hasher.verify_password("mmholAhsbC123*", &hash).unwrap())
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = hash, verify
+}
+criterion_main!(benches);
@@ -0,0 +1,131 @@
+//! Module providing DNS checks for email domains.
+use crate::primitives::Error;
+use hickory_resolver::{net::NetError, TokioResolver};
+use std::future::Future;
+
+/// A trait for resolvers looking up the records of a domain.
+pub trait DomainResolver {
+    /// Confirms whether the provided domain has at least one MX record.
+    ///
+    /// Returns `Error::DnsLookupError` if the lookup failed.
+    fn has_mx_records(&self, domain: &str) -> impl Future<Output = Result<bool, Error>> + Send;
+
+    /// Confirms whether the provided domain has at least one A or AAAA record.
+    ///
+    /// Returns `Error::DnsLookupError` if the lookup failed.
+    fn has_address_records(&self, domain: &str)
+        -> impl Future<Output = Result<bool, Error>> + Send;
+}
+
+/// Domain resolver using the system's DNS configuration on the tokio runtime.
+#[derive(Clone)]
+pub struct HickoryResolver {
+    resolver: TokioResolver,
+}
+
+impl HickoryResolver {
+    /// Initializes a new resolver with the system's DNS configuration.
+    ///
+    /// Returns `Error::DnsLookupError` if the configuration can't be read.
+    pub fn new() -> Result<Self, Error> {
+        let resolver = TokioResolver::builder_tokio()
+            .and_then(|builder| builder.build())
+            .map_err(|e| Error::DnsLookupError(e.to_string()))?;
+        Ok(Self { resolver })
+    }
+}
+
+/// Converts the result of a lookup, a missing record or domain isn't an error.
+fn has_records<T>(
+    result: Result<T, NetError>,
+    is_empty: impl FnOnce(T) -> bool,
+) -> Result<bool, Error> {
+    match result {
+        Ok(lookup) => Ok(!is_empty(lookup)),
+        Err(e) if e.is_no_records_found() || e.is_nx_domain() => Ok(false),
+        Err(e) => Err(Error::DnsLookupError(e.to_string())),
+    }
+}
+
+impl DomainResolver for HickoryResolver {
+    async fn has_mx_records(&self, domain: &str) -> Result<bool, Error> {
+        has_records(self.resolver.mx_lookup(domain).await, |lookup| {
+            lookup.answers().is_empty()
+        })
+    }
+
+    async fn has_address_records(&self, domain: &str) -> Result<bool, Error> {
+        has_records(self.resolver.lookup_ip(domain).await, |lookup| {
+            lookup.iter().next().is_none()
+        })
+    }
+}
+
+/// Confirms whether the provided domain can receive mail, i.e. it has an MX record
+/// or, as a fallback, an address record.
+pub async fn accepts_mail(domain: &str, resolver: &impl DomainResolver) -> Result<bool, Error> {
+    // Ensure that the domain is treated as fully qualified and not searched locally.
+    let domain = format!("{}.", domain.trim_end_matches('.'));
+    if resolver.has_mx_records(&domain).await? {
+        return Ok(true);
+    }
+    resolver.has_address_records(&domain).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::{Email, ValidationError};
+
+    /// Domain resolver returning fixed results.
+    struct MockResolver {
+        mx: bool,
+        address: bool,
+    }
+
+    impl DomainResolver for MockResolver {
+        async fn has_mx_records(&self, _domain: &str) -> Result<bool, Error> {
+            Ok(self.mx)
+        }
+
+        async fn has_address_records(&self, _domain: &str) -> Result<bool, Error> {
+            Ok(self.address)
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_mail_works() {
+        for (mx, address, expected) in [
+            (true, false, true),
+            (false, true, true),
+            (false, false, false),
+        ] {
+            let resolver = MockResolver { mx, address };
+            assert_eq!(accepts_mail("example.com", &resolver).await, Ok(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn email_mx_check_works() {
+        let resolver = MockResolver {
+            mx: false,
+            address: false,
+        };
+        assert_eq!(
+            Email::new_with_mx_check("john.doe@example.com", &resolver).await,
+            Err(Error::Validation(ValidationError::UnresolvableDomain))
+        );
+        assert_eq!(
+            Email::new_with_mx_check("john.doe", &resolver).await,
+            Err(Error::Validation(ValidationError::Email))
+        );
+
+        let resolver = MockResolver {
+            mx: true,
+            address: false,
+        };
+        assert!(Email::new_with_mx_check("john.doe@example.com", &resolver)
+            .await
+            .is_ok());
+    }
+}
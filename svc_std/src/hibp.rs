@@ -0,0 +1,123 @@
+//! Module providing HaveIBeenPwned breached password checks.
+//!
+//! Uses the k-anonymity range API: only the first five characters of the
+//! password's SHA-1 hash are sent, the matching is done locally.
+use crate::primitives::Error;
+use sha1::{Digest, Sha1};
+use std::time::Duration;
+
+/// Base url of the HaveIBeenPwned range API.
+pub const RANGE_API_URL: &str = "https://api.pwnedpasswords.com/range/";
+
+/// Default time allowed to connect to the range API.
+pub const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Default time allowed to read the response of the range API.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A trait for clients of the HaveIBeenPwned range API.
+pub trait RangeClient {
+    /// Returns the response body of the range API for the provided five character
+    /// hash prefix, consisting of `SUFFIX:COUNT` lines.
+    ///
+    /// Returns `Error::BreachCheckError` if the lookup failed.
+    fn range(&self, prefix: &str) -> Result<String, Error>;
+}
+
+/// Range API client using a blocking http agent.
+///
+/// Lookups fail with `Error::BreachCheckError` when the API doesn't respond within
+/// the timeouts, so a hanging API can't block password validation.
+#[derive(Clone, Debug)]
+pub struct HibpClient {
+    agent: ureq::Agent,
+}
+
+impl Default for HibpClient {
+    fn default() -> Self {
+        Self::with_timeouts(CONNECT_TIMEOUT, READ_TIMEOUT)
+    }
+}
+
+impl HibpClient {
+    /// Initializes a new client with the default timeouts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Initializes a new client with the provided connect and read timeouts.
+    pub fn with_timeouts(connect: Duration, read: Duration) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout_connect(connect)
+                .timeout_read(read)
+                .build(),
+        }
+    }
+
+    fn get(&self, url: &str) -> Result<String, Error> {
+        self.agent
+            .get(url)
+            .call()
+            .map_err(|e| Error::BreachCheckError(e.to_string()))?
+            .into_string()
+            .map_err(|e| Error::BreachCheckError(e.to_string()))
+    }
+}
+
+impl RangeClient for HibpClient {
+    fn range(&self, prefix: &str) -> Result<String, Error> {
+        self.get(&format!("{RANGE_API_URL}{prefix}"))
+    }
+}
+
+/// Confirms whether the provided password appears in the breach corpus of the client.
+pub fn is_breached(password: &str, client: &impl RangeClient) -> Result<bool, Error> {
+    let hash: String = Sha1::digest(password.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect();
+    let (prefix, suffix) = hash.split_at(5);
+
+    Ok(client.range(prefix)?.lines().any(|line| {
+        line.split(':')
+            .next()
+            .is_some_and(|s| s.trim().eq_ignore_ascii_case(suffix))
+    }))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Range client returning a fixed response.
+    pub(crate) struct MockClient(pub(crate) &'static str);
+
+    impl RangeClient for MockClient {
+        fn range(&self, _prefix: &str) -> Result<String, Error> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn is_breached_works() {
+        // SHA-1 of "password" is 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8.
+        let client = MockClient(
+            "003D68EB55068C33ACE09247EE4C639306B:3\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:9545824",
+        );
+        assert_eq!(is_breached("password", &client), Ok(true));
+        assert_eq!(is_breached("mmholAhsbC123*", &client), Ok(false));
+    }
+
+    #[test]
+    fn hibp_client_times_out() {
+        // The listener accepts connections, but never responds.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/range/5BAA6", listener.local_addr().unwrap());
+
+        let client = HibpClient::with_timeouts(Duration::from_secs(1), Duration::from_millis(100));
+        let started = std::time::Instant::now();
+        assert!(matches!(client.get(&url), Err(Error::BreachCheckError(_))));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
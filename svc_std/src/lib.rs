@@ -2,6 +2,12 @@
 //!
 //! The library provides primitives, traits and tooling.
 
+#[cfg(feature = "dns")]
+pub mod dns;
+#[cfg(feature = "hibp")]
+pub mod hibp;
+pub mod lockout;
 pub mod password_hasher;
 pub mod primitives;
+pub mod store;
 pub mod traits;
@@ -0,0 +1,298 @@
+//! Module providing brute-force protection for authenticatable objects.
+//!
+//! Failed attempts are tracked per key in an injectable `AttemptStore`, so they
+//! can be backed by shared storage like Redis.
+use crate::{
+    primitives::Error,
+    traits::{authenticatable, Authenticatable},
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+/// Policy for locking accounts after repeated failed attempts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LockoutPolicy {
+    /// Number of failed attempts within the lockout duration that lock the account.
+    pub max_attempts: u32,
+
+    /// Duration during which failed attempts count and the account stays locked.
+    pub lockout_duration: Duration,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            lockout_duration: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// A trait for storing failed authentication attempts.
+pub trait AttemptStore {
+    /// Returns the number of failed attempts for the key since the provided time.
+    fn failed_attempts(&self, key: &str, since: SystemTime) -> u32;
+
+    /// Records a failed attempt for the key at the provided time and returns the
+    /// number of failed attempts since the provided time, including this one.
+    ///
+    /// Recording and counting must be atomic, e.g. an increment with expiry on Redis,
+    /// so parallel attempts can't exceed the maximum. Attempts before `since` no
+    /// longer count and may be dropped.
+    fn record_failure(&self, key: &str, at: SystemTime, since: SystemTime) -> u32;
+
+    /// Clears all failed attempts for the key.
+    fn clear(&self, key: &str);
+}
+
+impl<S: AttemptStore + ?Sized> AttemptStore for &S {
+    fn failed_attempts(&self, key: &str, since: SystemTime) -> u32 {
+        (**self).failed_attempts(key, since)
+    }
+
+    fn record_failure(&self, key: &str, at: SystemTime, since: SystemTime) -> u32 {
+        (**self).record_failure(key, at, since)
+    }
+
+    fn clear(&self, key: &str) {
+        (**self).clear(key)
+    }
+}
+
+impl<S: AttemptStore + ?Sized> AttemptStore for Arc<S> {
+    fn failed_attempts(&self, key: &str, since: SystemTime) -> u32 {
+        (**self).failed_attempts(key, since)
+    }
+
+    fn record_failure(&self, key: &str, at: SystemTime, since: SystemTime) -> u32 {
+        (**self).record_failure(key, at, since)
+    }
+
+    fn clear(&self, key: &str) {
+        (**self).clear(key)
+    }
+}
+
+/// In-memory implementation of the AttemptStore trait.
+///
+/// Attempts outside the window are dropped when a new failure is recorded,
+/// so the attempts of a key under attack don't grow without bound.
+#[derive(Debug, Default)]
+pub struct InMemoryAttemptStore {
+    attempts: Mutex<HashMap<String, Vec<SystemTime>>>,
+}
+
+impl InMemoryAttemptStore {
+    /// Initializes a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl AttemptStore for InMemoryAttemptStore {
+    fn failed_attempts(&self, key: &str, since: SystemTime) -> u32 {
+        let attempts = self.attempts.lock().unwrap_or_else(|e| e.into_inner());
+        attempts
+            .get(key)
+            .map_or(0, |a| a.iter().filter(|&&at| at >= since).count() as u32)
+    }
+
+    fn record_failure(&self, key: &str, at: SystemTime, since: SystemTime) -> u32 {
+        let mut attempts = self.attempts.lock().unwrap_or_else(|e| e.into_inner());
+        let attempts = attempts.entry(key.to_string()).or_default();
+        attempts.retain(|&a| a >= since);
+        attempts.push(at);
+        attempts.len() as u32
+    }
+
+    fn clear(&self, key: &str) {
+        let mut attempts = self.attempts.lock().unwrap_or_else(|e| e.into_inner());
+        attempts.remove(key);
+    }
+}
+
+/// Wrapper that locks an authenticatable object after repeated failed attempts.
+///
+/// Returns `Error::AccountLocked` without confirming the password while the
+/// number of failed attempts within the lockout duration has reached the maximum.
+/// Each attempt is recorded as a failure before the password is confirmed, so
+/// parallel attempts can't exceed the maximum. A successful confirmation clears
+/// the failed attempts.
+///
+/// ```rust
+/// # use crate::svc_std::{lockout::{InMemoryAttemptStore, LockoutPolicy, Throttled}, primitives::Error, traits::{authenticatable, Authenticatable}};
+/// # use std::time::Duration;
+/// struct Account;
+/// impl Authenticatable<Error> for Account {
+///     fn confirm_password(&self, password: &str) -> authenticatable::Result<Error> {
+///         if password != "testtest" { return Err(Error::InvalidPassword) }
+///         Ok(())
+///     }
+/// }
+///
+/// let store = InMemoryAttemptStore::new();
+/// let policy = LockoutPolicy { max_attempts: 2, lockout_duration: Duration::from_secs(60) };
+/// let account = Throttled::new(Account, "john.doe@example.com", policy, &store);
+/// assert_eq!(account.confirm_password("blabla"), Err(Error::InvalidPassword));
+/// assert_eq!(account.confirm_password("blabla"), Err(Error::InvalidPassword));
+/// assert_eq!(account.confirm_password("testtest"), Err(Error::AccountLocked));
+/// ```
+#[derive(Debug)]
+pub struct Throttled<A, S> {
+    inner: A,
+    key: String,
+    policy: LockoutPolicy,
+    store: S,
+}
+
+impl<A: Authenticatable<Error>, S: AttemptStore> Throttled<A, S> {
+    /// Initializes a new wrapper, tracking failed attempts under the provided key.
+    pub fn new(inner: A, key: impl Into<String>, policy: LockoutPolicy, store: S) -> Self {
+        Self {
+            inner,
+            key: key.into(),
+            policy,
+            store,
+        }
+    }
+
+    /// Returns the wrapped authenticatable object.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+
+    /// Confirms the provided password as if it were attempted at the provided time.
+    pub fn confirm_password_at(
+        &self,
+        password: &str,
+        now: SystemTime,
+    ) -> authenticatable::Result<Error> {
+        let since = now
+            .checked_sub(self.policy.lockout_duration)
+            .unwrap_or(SystemTime::UNIX_EPOCH);
+        if self.store.failed_attempts(&self.key, since) >= self.policy.max_attempts {
+            return Err(Error::AccountLocked);
+        }
+
+        // Reserves the attempt, the reservation is cleared if the password matches.
+        if self.store.record_failure(&self.key, now, since) > self.policy.max_attempts {
+            return Err(Error::AccountLocked);
+        }
+        self.inner.confirm_password(password)?;
+        self.store.clear(&self.key);
+        Ok(())
+    }
+}
+
+impl<A: Authenticatable<Error>, S: AttemptStore> Authenticatable<Error> for Throttled<A, S> {
+    fn confirm_password(&self, password: &str) -> authenticatable::Result<Error> {
+        self.confirm_password_at(password, SystemTime::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Account;
+
+    impl Authenticatable<Error> for Account {
+        fn confirm_password(&self, password: &str) -> authenticatable::Result<Error> {
+            if password != "testtest" {
+                return Err(Error::InvalidPassword);
+            }
+            Ok(())
+        }
+    }
+
+    fn policy() -> LockoutPolicy {
+        LockoutPolicy {
+            max_attempts: 3,
+            lockout_duration: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn lockout_works() {
+        let store = InMemoryAttemptStore::new();
+        let account = Throttled::new(Account, "john", policy(), &store);
+        let start = SystemTime::now();
+
+        for i in 0..3 {
+            let at = start + Duration::from_secs(i);
+            assert_eq!(
+                account.confirm_password_at("blabla", at),
+                Err(Error::InvalidPassword)
+            );
+        }
+
+        // Ensure that the account is locked, even for the correct password.
+        let at = start + Duration::from_secs(10);
+        assert_eq!(
+            account.confirm_password_at("testtest", at),
+            Err(Error::AccountLocked)
+        );
+
+        // Ensure that other accounts sharing the store aren't affected.
+        let other = Throttled::new(Account, "jane", policy(), &store);
+        assert!(other.confirm_password_at("testtest", at).is_ok());
+
+        // Ensure that the account recovers once the failures are outside the window.
+        let at = start + Duration::from_secs(61);
+        assert!(account.confirm_password_at("testtest", at).is_ok());
+    }
+
+    #[test]
+    fn success_clears_failures() {
+        let store = Arc::new(InMemoryAttemptStore::new());
+        let account = Throttled::new(Account, "john", policy(), store.clone());
+        let now = SystemTime::now();
+
+        assert!(account.confirm_password_at("blabla", now).is_err());
+        assert!(account.confirm_password_at("blabla", now).is_err());
+        assert!(account.confirm_password_at("testtest", now).is_ok());
+        assert_eq!(store.failed_attempts("john", SystemTime::UNIX_EPOCH), 0);
+        assert!(account.confirm_password("blabla").is_err());
+        assert_eq!(store.failed_attempts("john", SystemTime::UNIX_EPOCH), 1);
+    }
+
+    #[test]
+    fn parallel_attempts_are_bounded() {
+        let store = InMemoryAttemptStore::new();
+        let account = Throttled::new(Account, "john", policy(), &store);
+        let now = SystemTime::now();
+        let barrier = std::sync::Barrier::new(10);
+
+        let results: Vec<_> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..10)
+                .map(|_| {
+                    s.spawn(|| {
+                        barrier.wait();
+                        account.confirm_password_at("blabla", now)
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        let guesses = results
+            .iter()
+            .filter(|r| **r == Err(Error::InvalidPassword))
+            .count();
+        assert_eq!(guesses, 3);
+    }
+
+    #[test]
+    fn in_memory_store_prunes_old_attempts() {
+        let store = InMemoryAttemptStore::new();
+        let start = SystemTime::now();
+        for i in 0..100 {
+            let at = start + Duration::from_secs(i * 60);
+            let since = at - Duration::from_secs(90);
+            assert!(store.record_failure("john", at, since) <= 2);
+        }
+        assert_eq!(store.attempts.lock().unwrap()["john"].len(), 2);
+    }
+}
@@ -1,11 +1,17 @@
-use crate::traits::{password_hasher::Error, PasswordHasher};
+use super::{parse_phc, verification_error};
+use crate::traits::{password_hasher::Error, ConfiguredPasswordHasher, PasswordHasher};
 use argon2::{
     password_hash::{
-        rand_core::OsRng, Error as ArgonError, PasswordHash, PasswordHasher as CorePasswordHasher,
+        rand_core::OsRng, Error as ArgonError, Output, PasswordHasher as CorePasswordHasher,
         PasswordVerifier, SaltString,
     },
-    Argon2,
+    Algorithm, Argon2, Params, Version,
 };
+use std::time::{Duration, Instant};
+use zeroize::Zeroizing;
+
+/// Memory cost (in KiB) above which calibration only raises the number of iterations.
+const CALIBRATION_MAX_M_COST: u32 = 1024 * 1024;
 
 impl From<ArgonError> for Error {
     fn from(value: ArgonError) -> Self {
@@ -16,21 +22,479 @@ impl From<ArgonError> for Error {
     }
 }
 
+impl From<argon2::Error> for Error {
+    fn from(value: argon2::Error) -> Self {
+        Self::HashingError(value.to_string())
+    }
+}
+
 /// Implementation of the PasswordHasher trait using Argon2.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Argon2PasswordHasher;
+///
+/// The static `PasswordHasher` functions use the default Argon2 parameters.
+/// Use `Argon2PasswordHasher::with_params` to hash with custom cost parameters
+/// and `Argon2PasswordHasher::with_secret` to mix in a server-side secret (pepper).
+/// Hashes created with a secret can only be verified with the same secret.
+///
+/// New hashes use `argon2id` unless another variant is selected with
+/// `Argon2PasswordHasher::with_algorithm`. Verification uses the variant and
+/// version encoded in the hash, so `argon2i` and `argon2d` hashes always verify.
+///
+/// ```rust
+/// # use crate::svc_std::password_hasher::argon2::Argon2PasswordHasher;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hasher = Argon2PasswordHasher::with_params(32768, 3, 1)?;
+///     let hash = hasher.hash_password("mmholAhsbC123*")?;
+///     assert!(hash.contains("m=32768,t=3,p=1"));
+///     assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Eq, PartialEq)]
+pub struct Argon2PasswordHasher {
+    algorithm: Algorithm,
+    version: Version,
+    params: Params,
+    secret: Option<Zeroizing<Vec<u8>>>,
+}
 
-impl PasswordHasher for Argon2PasswordHasher {
-    fn hash(input: &str) -> Result<String, Error> {
+impl std::fmt::Debug for Argon2PasswordHasher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Argon2PasswordHasher")
+            .field("algorithm", &self.algorithm)
+            .field("version", &self.version)
+            .field("params", &self.params)
+            .field("secret", &self.secret.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+impl Argon2PasswordHasher {
+    /// Initializes a new hasher with the provided memory cost (in KiB),
+    /// number of iterations and degree of parallelism.
+    ///
+    /// Returns `Error::HashingError` if the parameters are out of range.
+    pub fn with_params(m_cost: u32, t_cost: u32, p_cost: u32) -> Result<Self, Error> {
+        Ok(Self {
+            params: Params::new(m_cost, t_cost, p_cost, None)?,
+            ..Self::default()
+        })
+    }
+
+    /// Sets the Argon2 variant and version used for new hashes.
+    pub fn with_algorithm(self, algorithm: Algorithm, version: Version) -> Self {
+        Self {
+            algorithm,
+            version,
+            ..self
+        }
+    }
+
+    /// Returns the Argon2 variant used for new hashes.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the Argon2 version used for new hashes.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
+    /// Sets the secret key (pepper) that is mixed into hashing and verification.
+    pub fn with_secret(self, secret: &[u8]) -> Self {
+        Self {
+            secret: Some(Zeroizing::new(secret.to_vec())),
+            ..self
+        }
+    }
+
+    /// Returns the Argon2 parameters used for hashing.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    fn argon2(&self) -> Result<Argon2<'_>, Error> {
+        match &self.secret {
+            Some(secret) => Ok(Argon2::new_with_secret(
+                secret,
+                self.algorithm,
+                self.version,
+                self.params.clone(),
+            )?),
+            None => Ok(Argon2::new(
+                self.algorithm,
+                self.version,
+                self.params.clone(),
+            )),
+        }
+    }
+
+    /// Returns the hash for the provided input using the configured parameters.
+    pub fn hash_password(&self, input: &str) -> Result<String, Error> {
         let salt = SaltString::generate(&mut OsRng);
-        Ok(Argon2::default()
+        Ok(self
+            .argon2()?
             .hash_password(input.as_bytes(), &salt)?
             .to_string())
     }
 
+    /// Confirms whether the provided password matches for the provided hash.
+    ///
+    /// The parameters encoded in the hash take precedence over the configured ones.
+    ///
+    /// Returns `Error::MalformedHash` if the stored hash can't be parsed.
+    ///
+    /// Only parsing the stored hash may return early. Every password is run through
+    /// the full Argon2 computation and the resulting output is compared in constant
+    /// time, so the duration doesn't depend on the correctness of the password.
+    pub fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        let parsed_hash = parse_phc(hash)?;
+        // Hashes of other algorithms would otherwise be reported as a mismatch.
+        Algorithm::try_from(parsed_hash.algorithm).map_err(|_| Error::MalformedHash)?;
+        self.argon2()?
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .map_err(verification_error)
+    }
+
+    /// Returns a decoy hash using the configured variant, version and parameters,
+    /// that no password matches.
+    pub fn dummy_hash(&self) -> String {
+        let salt = SaltString::encode_b64(b"svc_std dummy salt").expect("dummy salt is valid");
+        let output_len = self
+            .params
+            .output_len()
+            .unwrap_or(Params::DEFAULT_OUTPUT_LEN);
+        let output = Output::new(&vec![0u8; output_len]).expect("output length is valid");
+        format!(
+            "${}$v={}$m={},t={},p={}${salt}${output}",
+            self.algorithm,
+            u32::from(self.version),
+            self.params.m_cost(),
+            self.params.t_cost(),
+            self.params.p_cost(),
+        )
+    }
+
+    /// Runs a full verification against a decoy hash, always returning
+    /// `Error::InvalidPassword`.
+    ///
+    /// Call this when a sign in targets an unknown account, so the response takes
+    /// as long as for an existing account and doesn't reveal which accounts exist:
+    ///
+    /// ```rust
+    /// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, traits::password_hasher::Error};
+    /// # fn find_hash(_email: &str) -> Option<String> { None }
+    /// fn sign_in(hasher: &Argon2PasswordHasher, email: &str, password: &str) -> Result<(), Error> {
+    ///     match find_hash(email) {
+    ///         Some(hash) => hasher.verify_password(password, &hash),
+    ///         None => hasher.verify_dummy(password),
+    ///     }
+    /// }
+    ///
+    /// let hasher = Argon2PasswordHasher::default();
+    /// assert_eq!(sign_in(&hasher, "john.doe@example.com", "mmholAhsbC123*"), Err(Error::InvalidPassword));
+    /// ```
+    ///
+    /// Use the same hasher configuration as for stored hashes, so both paths cost the same.
+    pub fn verify_dummy(&self, password: &str) -> Result<(), Error> {
+        match self.verify_password(password, &self.dummy_hash()) {
+            Ok(()) => Err(Error::InvalidPassword),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Confirms whether the provided hash uses a different variant or version,
+    /// or any lower cost parameter than the configured ones.
+    pub fn check_rehash(&self, hash: &str) -> Result<bool, Error> {
+        let parsed_hash = parse_phc(hash)?;
+        let algorithm =
+            Algorithm::try_from(parsed_hash.algorithm).map_err(|_| Error::MalformedHash)?;
+        if algorithm != self.algorithm {
+            return Ok(true);
+        }
+        let version = match parsed_hash.version {
+            Some(version) => Version::try_from(version).map_err(|_| Error::MalformedHash)?,
+            None => Version::V0x10,
+        };
+        if version != self.version {
+            return Ok(true);
+        }
+        let params = Params::try_from(&parsed_hash).map_err(|_| Error::MalformedHash)?;
+        Ok(params.m_cost() < self.params.m_cost()
+            || params.t_cost() < self.params.t_cost()
+            || params.p_cost() < self.params.p_cost())
+    }
+}
+
+impl PasswordHasher for Argon2PasswordHasher {
+    fn hash(input: &str) -> Result<String, Error> {
+        Self::default().hash_password(input)
+    }
+
     fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
-        let parsed_hash = PasswordHash::new(hash)?;
-        Argon2::default().verify_password(password.as_bytes(), &parsed_hash)?;
-        Ok(())
+        Self::default().verify_password(password, hash)
+    }
+
+    fn needs_rehash(hash: &str) -> Result<bool, Error> {
+        Self::default().check_rehash(hash)
+    }
+}
+
+impl ConfiguredPasswordHasher for Argon2PasswordHasher {
+    fn hash_password(&self, input: &str) -> Result<String, Error> {
+        Argon2PasswordHasher::hash_password(self, input)
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        Argon2PasswordHasher::verify_password(self, password, hash)
+    }
+}
+
+/// Returns Argon2id parameters for which hashing takes about the provided
+/// target time on the current hardware.
+///
+/// Starting from the default parameters, the memory cost is doubled as long as
+/// hashing stays below half the target, up to 1 GiB. The number of iterations
+/// is then scaled to close the remaining gap. The parameters never fall below
+/// the defaults, so hardware slower than the target yields the defaults.
+///
+/// Calibration hashes several times, run it once on startup and reuse the result.
+///
+/// ```rust,no_run
+/// # use crate::svc_std::password_hasher::argon2::{calibrate, Argon2PasswordHasher};
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let params = calibrate(Duration::from_millis(250));
+///     let hasher =
+///         Argon2PasswordHasher::with_params(params.m_cost(), params.t_cost(), params.p_cost())?;
+/// #    Ok(())
+/// # }
+/// ```
+pub fn calibrate(target: Duration) -> Params {
+    calibrate_with(target, |m_cost, t_cost| {
+        let params = Params::new(m_cost, t_cost, Params::DEFAULT_P_COST, None)
+            .expect("calibrated params are in range");
+        let mut output = [0u8; Params::DEFAULT_OUTPUT_LEN];
+        let start = Instant::now();
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(b"calibration", b"calibration salt", &mut output)
+            .expect("calibration input is valid");
+        start.elapsed()
+    })
+}
+
+/// Calibrates with the provided function measuring the hashing time for a
+/// memory cost and number of iterations.
+fn calibrate_with(target: Duration, mut measure: impl FnMut(u32, u32) -> Duration) -> Params {
+    let (mut m_cost, mut t_cost) = (Params::DEFAULT_M_COST, Params::DEFAULT_T_COST);
+    let mut elapsed = measure(m_cost, t_cost);
+    while elapsed * 2 <= target && m_cost * 2 <= CALIBRATION_MAX_M_COST {
+        m_cost *= 2;
+        elapsed = measure(m_cost, t_cost);
+    }
+
+    let ratio = target.as_secs_f64() / elapsed.as_secs_f64();
+    t_cost = t_cost.max((t_cost as f64 * ratio).round() as u32);
+    Params::new(m_cost, t_cost, Params::DEFAULT_P_COST, None)
+        .expect("calibrated params are in range")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_params_work() {
+        let hasher = Argon2PasswordHasher::with_params(65536, 3, 2).unwrap();
+        let hash = hasher.hash_password("mmholAhsbC123*").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(hash.contains("m=65536,t=3,p=2"));
+        assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            hasher.verify_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+
+        // Ensure that hashes with custom parameters verify through the static api.
+        assert!(Argon2PasswordHasher::confirm_password("mmholAhsbC123*", &hash).is_ok());
+    }
+
+    #[test]
+    fn needs_rehash_works() {
+        let low = Argon2PasswordHasher::with_params(8192, 1, 1).unwrap();
+        let high = Argon2PasswordHasher::with_params(32768, 2, 1).unwrap();
+        let hash = low.hash_password("mmholAhsbC123*").unwrap();
+
+        assert_eq!(high.check_rehash(&hash), Ok(true));
+        assert_eq!(low.check_rehash(&hash), Ok(false));
+        assert_eq!(Argon2PasswordHasher::needs_rehash(&hash), Ok(true));
+
+        let hash = Argon2PasswordHasher::hash("mmholAhsbC123*").unwrap();
+        assert_eq!(Argon2PasswordHasher::needs_rehash(&hash), Ok(false));
+        assert_eq!(
+            Argon2PasswordHasher::needs_rehash("garbage"),
+            Err(Error::MalformedHash)
+        );
+    }
+
+    #[test]
+    fn wrong_password_goes_through_verifier() {
+        let hash = Argon2PasswordHasher::hash("mmholAhsbC123*").unwrap();
+
+        // Only a full verification yields `InvalidPassword`, parse failures yield
+        // `MalformedHash` instead.
+        for password in ["", "m", "mmholAhsbC123", "mmholAhsbC123*+", "blabla"] {
+            assert_eq!(
+                Argon2PasswordHasher::confirm_password(password, &hash),
+                Err(Error::InvalidPassword)
+            );
+        }
+        assert_eq!(
+            Argon2PasswordHasher::confirm_password("mmholAhsbC123*", &hash[1..]),
+            Err(Error::MalformedHash)
+        );
+    }
+
+    #[test]
+    fn secret_works() {
+        let peppered = Argon2PasswordHasher::default().with_secret(b"pepper");
+        let hash = peppered.hash_password("mmholAhsbC123*").unwrap();
+        assert!(peppered.verify_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            peppered.verify_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+
+        // Ensure that verification fails without or with a different secret.
+        assert_eq!(
+            Argon2PasswordHasher::confirm_password("mmholAhsbC123*", &hash),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(
+            Argon2PasswordHasher::default()
+                .with_secret(b"salt")
+                .verify_password("mmholAhsbC123*", &hash),
+            Err(Error::InvalidPassword)
+        );
+
+        // Ensure that the secret doesn't leak through debug output.
+        assert!(format!("{peppered:?}").contains(r#"secret: Some("***")"#));
+    }
+
+    #[test]
+    fn algorithm_variants_work() {
+        for (algorithm, label) in [
+            (Algorithm::Argon2i, "$argon2i$"),
+            (Algorithm::Argon2d, "$argon2d$"),
+            (Algorithm::Argon2id, "$argon2id$"),
+        ] {
+            let hasher = Argon2PasswordHasher::with_params(8192, 1, 1)
+                .unwrap()
+                .with_algorithm(algorithm, Version::V0x13);
+            let hash = hasher.hash_password("mmholAhsbC123*").unwrap();
+            assert!(hash.starts_with(label), "{hash}");
+            assert!(hash.contains("v=19"));
+            assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+            assert_eq!(hasher.check_rehash(&hash), Ok(false));
+
+            // Ensure that the variant is detected from the hash on verification.
+            assert!(Argon2PasswordHasher::confirm_password("mmholAhsbC123*", &hash).is_ok());
+            assert_eq!(
+                Argon2PasswordHasher::confirm_password("blabla", &hash),
+                Err(Error::InvalidPassword)
+            );
+        }
+
+        let legacy =
+            Argon2PasswordHasher::default().with_algorithm(Algorithm::Argon2i, Version::V0x10);
+        let hash = legacy.hash_password("mmholAhsbC123*").unwrap();
+        assert!(hash.starts_with("$argon2i$v=16$"));
+        assert!(Argon2PasswordHasher::confirm_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(Argon2PasswordHasher::needs_rehash(&hash), Ok(true));
+    }
+
+    #[test]
+    fn verify_dummy_works() {
+        let hasher = Argon2PasswordHasher::with_params(8192, 1, 1).unwrap();
+        let hash = hasher.dummy_hash();
+        assert!(hash.starts_with("$argon2id$v=19$m=8192,t=1,p=1$"));
+        assert_eq!(hasher.check_rehash(&hash), Ok(false));
+
+        for password in ["", "mmholAhsbC123*", "svc_std dummy salt"] {
+            assert_eq!(hasher.verify_dummy(password), Err(Error::InvalidPassword));
+        }
+        assert_eq!(
+            Argon2PasswordHasher::default()
+                .with_secret(b"pepper")
+                .verify_dummy("mmholAhsbC123*"),
+            Err(Error::InvalidPassword)
+        );
+    }
+
+    #[test]
+    fn invalid_params_fail() {
+        assert!(matches!(
+            Argon2PasswordHasher::with_params(1, 0, 1),
+            Err(Error::HashingError(_))
+        ));
+    }
+
+    #[test]
+    fn calibrate_scales_params() {
+        // Models hashing as taking 1µs per KiB per iteration.
+        let measure =
+            |m_cost: u32, t_cost: u32| Duration::from_micros(m_cost as u64 * t_cost as u64);
+
+        // The memory cost doubles to 622ms, after which the iterations close the gap.
+        let params = calibrate_with(Duration::from_secs(1), measure);
+        assert_eq!(params.m_cost(), Params::DEFAULT_M_COST * 16);
+        assert_eq!(params.t_cost(), 3);
+        assert_eq!(params.p_cost(), Params::DEFAULT_P_COST);
+
+        // Ensure that the memory cost is capped, leaving the rest to the iterations.
+        let params = calibrate_with(Duration::from_secs(100), measure);
+        assert_eq!(params.m_cost(), Params::DEFAULT_M_COST * 32);
+        assert!(params.m_cost() <= CALIBRATION_MAX_M_COST);
+        let elapsed = measure(params.m_cost(), params.t_cost());
+        assert!(elapsed.abs_diff(Duration::from_secs(100)) < Duration::from_secs(1));
+
+        // Ensure that unreachable targets yield the defaults.
+        assert_eq!(calibrate_with(Duration::ZERO, measure), Params::default());
+        let slow = |_, _| Duration::from_secs(10);
+        assert_eq!(
+            calibrate_with(Duration::from_secs(1), slow),
+            Params::default()
+        );
+    }
+
+    /// Measures real hashing times, which is slow and sensitive to load.
+    #[test]
+    #[ignore]
+    fn calibrate_hits_target() {
+        let time = |params: &Params| {
+            let hasher = Argon2PasswordHasher::with_params(
+                params.m_cost(),
+                params.t_cost(),
+                params.p_cost(),
+            )
+            .unwrap();
+            let start = Instant::now();
+            hasher.hash_password("mmholAhsbC123*").unwrap();
+            start.elapsed()
+        };
+
+        // Aim well above the default cost, so the calibration has room to scale.
+        let target = time(&Params::default()) * 4;
+        let params = calibrate(target);
+        assert!(params.m_cost() > Params::DEFAULT_M_COST);
+        assert!(params.t_cost() >= Params::DEFAULT_T_COST);
+
+        let elapsed = time(&params);
+        assert!(
+            elapsed >= target / 2 && elapsed <= target * 2,
+            "{elapsed:?} not within tolerance of {target:?}"
+        );
+
+        // Ensure that unreachable targets yield the defaults.
+        assert_eq!(calibrate(Duration::ZERO), Params::default());
     }
 }
@@ -0,0 +1,140 @@
+use crate::traits::{password_hasher::Error, ConfiguredPasswordHasher, PasswordHasher};
+use bcrypt::{BcryptError, HashParts};
+
+impl From<BcryptError> for Error {
+    fn from(value: BcryptError) -> Self {
+        Self::HashingError(value.to_string())
+    }
+}
+
+/// The default bcrypt cost factor.
+pub const DEFAULT_COST: u32 = 12;
+
+/// Implementation of the PasswordHasher trait using bcrypt.
+///
+/// The static `PasswordHasher` functions use `DEFAULT_COST`.
+/// Use `BcryptPasswordHasher::with_cost` to hash with a custom cost factor.
+///
+/// ```rust
+/// # use crate::svc_std::password_hasher::bcrypt::BcryptPasswordHasher;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hasher = BcryptPasswordHasher::with_cost(4)?;
+///     let hash = hasher.hash_password("mmholAhsbC123*")?;
+///     assert!(hash.starts_with("$2b$04$"));
+///     assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BcryptPasswordHasher {
+    cost: u32,
+}
+
+impl Default for BcryptPasswordHasher {
+    fn default() -> Self {
+        Self { cost: DEFAULT_COST }
+    }
+}
+
+impl BcryptPasswordHasher {
+    /// Initializes a new hasher with the provided cost factor.
+    ///
+    /// Returns `Error::HashingError` if the cost is outside the allowed 4-31 range.
+    pub fn with_cost(cost: u32) -> Result<Self, Error> {
+        if !(4..=31).contains(&cost) {
+            return Err(BcryptError::CostNotAllowed(cost).into());
+        }
+        Ok(Self { cost })
+    }
+
+    /// Returns the cost factor used for hashing.
+    pub fn cost(&self) -> u32 {
+        self.cost
+    }
+
+    /// Returns the hash for the provided input using the configured cost factor.
+    pub fn hash_password(&self, input: &str) -> Result<String, Error> {
+        Ok(bcrypt::hash(input, self.cost)?)
+    }
+
+    /// Confirms whether the provided password matches for the provided hash.
+    ///
+    /// Returns `Error::MalformedHash` if the stored hash can't be parsed.
+    pub fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        match bcrypt::verify(password, hash).map_err(|_| Error::MalformedHash)? {
+            true => Ok(()),
+            false => Err(Error::InvalidPassword),
+        }
+    }
+
+    /// Confirms whether the provided hash uses a lower cost factor than the configured one.
+    pub fn check_rehash(&self, hash: &str) -> Result<bool, Error> {
+        let parts: HashParts = hash.parse().map_err(|_| Error::MalformedHash)?;
+        Ok(parts.get_cost() < self.cost)
+    }
+}
+
+impl PasswordHasher for BcryptPasswordHasher {
+    fn hash(input: &str) -> Result<String, Error> {
+        Self::default().hash_password(input)
+    }
+
+    fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
+        Self::default().verify_password(password, hash)
+    }
+
+    fn needs_rehash(hash: &str) -> Result<bool, Error> {
+        Self::default().check_rehash(hash)
+    }
+}
+
+impl ConfiguredPasswordHasher for BcryptPasswordHasher {
+    fn hash_password(&self, input: &str) -> Result<String, Error> {
+        BcryptPasswordHasher::hash_password(self, input)
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        BcryptPasswordHasher::verify_password(self, password, hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_works() {
+        let hasher = BcryptPasswordHasher::with_cost(4).unwrap();
+        let hash = hasher.hash_password("mmholAhsbC123*").unwrap();
+        assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            hasher.verify_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(hasher.check_rehash(&hash), Ok(false));
+        assert_eq!(BcryptPasswordHasher::needs_rehash(&hash), Ok(true));
+    }
+
+    #[test]
+    fn external_hash_verifies() {
+        // Test vector from the OpenWall crypt_blowfish test suite.
+        let hash = "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW";
+        assert!(BcryptPasswordHasher::confirm_password("U*U", hash).is_ok());
+        assert_eq!(
+            BcryptPasswordHasher::confirm_password("U*U*", hash),
+            Err(Error::InvalidPassword)
+        );
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        assert!(matches!(
+            BcryptPasswordHasher::with_cost(32),
+            Err(Error::HashingError(_))
+        ));
+        assert!(matches!(
+            BcryptPasswordHasher::confirm_password("U*U", "garbage"),
+            Err(Error::MalformedHash)
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+use super::{
+    argon2::Argon2PasswordHasher, bcrypt::BcryptPasswordHasher, multi::MultiHasher,
+    pbkdf2::Pbkdf2PasswordHasher, scrypt::ScryptPasswordHasher,
+};
+use crate::traits::{password_hasher::Error, DynPasswordHasher, PasswordHasher};
+use std::marker::PhantomData;
+
+/// Adapter implementing `DynPasswordHasher` for a static `PasswordHasher`.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::{argon2::Argon2PasswordHasher, dynamic::DynHasher}, traits::DynPasswordHasher};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hasher: Box<dyn DynPasswordHasher> = Box::new(DynHasher::<Argon2PasswordHasher>::new());
+///     let hash = hasher.hash("mmholAhsbC123*")?;
+///     assert!(hasher.confirm_password("mmholAhsbC123*", &hash).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DynHasher<P: PasswordHasher> {
+    phantom: PhantomData<fn() -> P>,
+}
+
+impl<P: PasswordHasher> DynHasher<P> {
+    /// Initializes a new adapter for the hasher `P`.
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<P: PasswordHasher> DynPasswordHasher for DynHasher<P> {
+    fn hash(&self, input: &str) -> Result<String, Error> {
+        P::hash(input)
+    }
+
+    fn confirm_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        P::confirm_password(password, hash)
+    }
+
+    fn needs_rehash(&self, hash: &str) -> Result<bool, Error> {
+        P::needs_rehash(hash)
+    }
+}
+
+/// Returns the hasher with the provided name, e.g. from configuration.
+///
+/// Supported names are `argon2`, `bcrypt`, `scrypt` and `pbkdf2`, and `multi`
+/// for a `MultiHasher` preferring Argon2. Names are case-insensitive.
+///
+/// Returns `Error::HashingError` for unknown names.
+///
+/// ```rust
+/// # use crate::svc_std::password_hasher::dynamic::from_name;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hasher = from_name("argon2")?;
+///     assert!(hasher.hash("mmholAhsbC123*")?.starts_with("$argon2id$"));
+///     assert!(from_name("md5").is_err());
+/// #    Ok(())
+/// # }
+/// ```
+pub fn from_name(name: &str) -> Result<Box<dyn DynPasswordHasher>, Error> {
+    match name.to_ascii_lowercase().as_str() {
+        "argon2" => Ok(Box::new(DynHasher::<Argon2PasswordHasher>::new())),
+        "bcrypt" => Ok(Box::new(DynHasher::<BcryptPasswordHasher>::new())),
+        "scrypt" => Ok(Box::new(DynHasher::<ScryptPasswordHasher>::new())),
+        "pbkdf2" => Ok(Box::new(DynHasher::<Pbkdf2PasswordHasher>::new())),
+        "multi" => Ok(Box::new(
+            DynHasher::<MultiHasher<Argon2PasswordHasher>>::new(),
+        )),
+        _ => Err(Error::HashingError(format!(
+            "unknown password hasher: {name}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_name_selects_hasher() {
+        let hasher = from_name("Argon2").unwrap();
+        let hash = hasher.hash("mmholAhsbC123*").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert!(hasher.confirm_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            hasher.confirm_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(hasher.needs_rehash(&hash), Ok(false));
+
+        // Ensure that hashers can be selected and stored together at runtime.
+        let hashers: Vec<Box<dyn DynPasswordHasher>> = ["argon2", "multi"]
+            .map(|name| from_name(name).unwrap())
+            .into();
+        assert!(hashers
+            .iter()
+            .all(|hasher| hasher.confirm_password("mmholAhsbC123*", &hash).is_ok()));
+
+        assert!(matches!(from_name("md5"), Err(Error::HashingError(_))));
+    }
+}
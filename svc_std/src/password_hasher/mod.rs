@@ -1,2 +1,26 @@
 //! Module providing PasswordHasher implementations.
 pub mod argon2;
+pub mod bcrypt;
+pub mod dynamic;
+pub mod multi;
+pub mod pbkdf2;
+pub mod pool;
+pub mod scrypt;
+
+use crate::traits::password_hasher::Error;
+use ::argon2::password_hash::{Error as PhcError, PasswordHash};
+
+/// Parses the provided PHC string hash.
+fn parse_phc(hash: &str) -> Result<PasswordHash<'_>, Error> {
+    PasswordHash::new(hash).map_err(|_| Error::MalformedHash)
+}
+
+/// Converts errors of verifying against a parsed PHC string hash.
+///
+/// Apart from a mismatch, these errors stem from the parameters encoded in the hash.
+fn verification_error(value: PhcError) -> Error {
+    match value {
+        PhcError::Password => Error::InvalidPassword,
+        _ => Error::MalformedHash,
+    }
+}
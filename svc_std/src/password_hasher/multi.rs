@@ -0,0 +1,204 @@
+use super::{
+    argon2::Argon2PasswordHasher, bcrypt::BcryptPasswordHasher, pbkdf2::Pbkdf2PasswordHasher,
+    scrypt::ScryptPasswordHasher,
+};
+use crate::traits::{password_hasher::Error, PasswordHasher};
+use std::{any::TypeId, marker::PhantomData};
+
+/// Implementation of the PasswordHasher trait that verifies hashes of any supported
+/// algorithm, useful while migrating between hashers.
+///
+/// New hashes always use the preferred hasher `P`. On confirmation the backend is
+/// selected by the prefix of the stored hash: `$argon2` for Argon2, `$2a$`, `$2b$`,
+/// `$2x$` or `$2y$` for bcrypt, `$scrypt$` for scrypt and `$pbkdf2` for PBKDF2.
+/// Hashes with any other
+/// prefix are handled by the preferred hasher, so custom hashers can be preferred.
+/// Hashes of any other backend than the preferred one need a rehash.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::{argon2::Argon2PasswordHasher, bcrypt::BcryptPasswordHasher, multi::MultiHasher}, traits::PasswordHasher};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     type Hasher = MultiHasher<Argon2PasswordHasher>;
+///
+///     let legacy_hash = BcryptPasswordHasher::with_cost(4)?.hash_password("mmholAhsbC123*")?;
+///     assert!(Hasher::confirm_password("mmholAhsbC123*", &legacy_hash).is_ok());
+///     assert!(Hasher::needs_rehash(&legacy_hash)?);
+///     assert!(Hasher::hash("mmholAhsbC123*")?.starts_with("$argon2id$"));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MultiHasher<P: PasswordHasher> {
+    phantom: PhantomData<P>,
+}
+
+/// Password hasher backends supported by `MultiHasher`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Backend {
+    Argon2,
+    Bcrypt,
+    Scrypt,
+    Pbkdf2,
+}
+
+impl Backend {
+    /// Detects the backend from the prefix of the provided hash, if it's known.
+    fn detect(hash: &str) -> Option<Self> {
+        match hash {
+            h if h.starts_with("$argon2") => Some(Self::Argon2),
+            h if ["$2a$", "$2b$", "$2x$", "$2y$"]
+                .iter()
+                .any(|prefix| h.starts_with(prefix)) =>
+            {
+                Some(Self::Bcrypt)
+            }
+            h if h.starts_with("$scrypt$") => Some(Self::Scrypt),
+            h if h.starts_with("$pbkdf2") => Some(Self::Pbkdf2),
+            _ => None,
+        }
+    }
+
+    fn type_id(self) -> TypeId {
+        match self {
+            Self::Argon2 => TypeId::of::<Argon2PasswordHasher>(),
+            Self::Bcrypt => TypeId::of::<BcryptPasswordHasher>(),
+            Self::Scrypt => TypeId::of::<ScryptPasswordHasher>(),
+            Self::Pbkdf2 => TypeId::of::<Pbkdf2PasswordHasher>(),
+        }
+    }
+}
+
+impl<P: PasswordHasher + 'static> PasswordHasher for MultiHasher<P> {
+    fn hash(input: &str) -> Result<String, Error> {
+        P::hash(input)
+    }
+
+    fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
+        match Backend::detect(hash) {
+            Some(Backend::Argon2) => Argon2PasswordHasher::confirm_password(password, hash),
+            Some(Backend::Bcrypt) => BcryptPasswordHasher::confirm_password(password, hash),
+            Some(Backend::Scrypt) => ScryptPasswordHasher::confirm_password(password, hash),
+            Some(Backend::Pbkdf2) => Pbkdf2PasswordHasher::confirm_password(password, hash),
+            None => P::confirm_password(password, hash),
+        }
+    }
+
+    fn needs_rehash(hash: &str) -> Result<bool, Error> {
+        match Backend::detect(hash) {
+            Some(backend) if backend.type_id() != TypeId::of::<P>() => Ok(true),
+            _ => P::needs_rehash(hash),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Hasher = MultiHasher<Argon2PasswordHasher>;
+
+    #[test]
+    fn confirm_password_dispatches() {
+        let argon2_hash = Argon2PasswordHasher::hash("mmholAhsbC123*").unwrap();
+        let bcrypt_hash = BcryptPasswordHasher::with_cost(4)
+            .unwrap()
+            .hash_password("mmholAhsbC123*")
+            .unwrap();
+        let scrypt_hash = ScryptPasswordHasher::with_params(10, 8, 1)
+            .unwrap()
+            .hash_password("mmholAhsbC123*")
+            .unwrap();
+        let pbkdf2_hash = Pbkdf2PasswordHasher::with_rounds(1000)
+            .unwrap()
+            .hash_password("mmholAhsbC123*")
+            .unwrap();
+        assert!(pbkdf2_hash.starts_with("$pbkdf2"));
+
+        for hash in [&argon2_hash, &bcrypt_hash, &scrypt_hash, &pbkdf2_hash] {
+            assert!(Hasher::confirm_password("mmholAhsbC123*", hash).is_ok());
+            assert_eq!(
+                Hasher::confirm_password("blabla", hash),
+                Err(Error::InvalidPassword)
+            );
+        }
+
+        // OpenWall test vector using the legacy `$2a$` prefix.
+        let hash = "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW";
+        assert!(Hasher::confirm_password("U*U", hash).is_ok());
+
+        assert_eq!(
+            Hasher::confirm_password("mmholAhsbC123*", "$md5$blabla"),
+            Err(Error::MalformedHash)
+        );
+    }
+
+    /// Hasher with a prefix unknown to `MultiHasher`.
+    struct ReverseHasher;
+    impl PasswordHasher for ReverseHasher {
+        fn hash(input: &str) -> Result<String, Error> {
+            Ok(format!(
+                "$reverse${}",
+                input.chars().rev().collect::<String>()
+            ))
+        }
+
+        fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
+            if !hash.starts_with("$reverse$") {
+                return Err(Error::MalformedHash);
+            }
+            match Self::hash(password)? == hash {
+                true => Ok(()),
+                false => Err(Error::InvalidPassword),
+            }
+        }
+
+        fn needs_rehash(hash: &str) -> Result<bool, Error> {
+            match hash.starts_with("$reverse$") {
+                true => Ok(false),
+                false => Err(Error::MalformedHash),
+            }
+        }
+    }
+
+    #[test]
+    fn custom_preferred_hasher_works() {
+        type Hasher = MultiHasher<ReverseHasher>;
+
+        let hash = Hasher::hash("mmholAhsbC123*").unwrap();
+        assert!(Hasher::confirm_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            Hasher::confirm_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(Hasher::needs_rehash(&hash), Ok(false));
+
+        // Ensure that hashes of known backends still dispatch and need a rehash.
+        let argon2_hash = Argon2PasswordHasher::hash("mmholAhsbC123*").unwrap();
+        assert!(Hasher::confirm_password("mmholAhsbC123*", &argon2_hash).is_ok());
+        assert_eq!(Hasher::needs_rehash(&argon2_hash), Ok(true));
+        assert_eq!(
+            Hasher::needs_rehash("$md5$blabla"),
+            Err(Error::MalformedHash)
+        );
+    }
+
+    #[test]
+    fn hash_uses_preferred_hasher() {
+        let hash = Hasher::hash("mmholAhsbC123*").unwrap();
+        assert!(hash.starts_with("$argon2id$"));
+        assert_eq!(Hasher::needs_rehash(&hash), Ok(false));
+
+        let bcrypt_hash = BcryptPasswordHasher::with_cost(4)
+            .unwrap()
+            .hash_password("mmholAhsbC123*")
+            .unwrap();
+        assert_eq!(Hasher::needs_rehash(&bcrypt_hash), Ok(true));
+
+        // Ensure that weaker hashes of the preferred backend are still detected.
+        let weak_hash = Argon2PasswordHasher::with_params(8192, 1, 1)
+            .unwrap()
+            .hash_password("mmholAhsbC123*")
+            .unwrap();
+        assert_eq!(Hasher::needs_rehash(&weak_hash), Ok(true));
+    }
+}
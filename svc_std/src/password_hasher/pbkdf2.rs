@@ -0,0 +1,154 @@
+use super::{parse_phc, verification_error};
+use crate::traits::{password_hasher::Error, ConfiguredPasswordHasher, PasswordHasher};
+use pbkdf2::{
+    password_hash::{
+        rand_core::OsRng, PasswordHasher as CorePasswordHasher, PasswordVerifier, SaltString,
+    },
+    Algorithm, Params, Pbkdf2,
+};
+
+/// Implementation of the PasswordHasher trait using PBKDF2-HMAC-SHA256.
+///
+/// PBKDF2 is implemented in pure Rust without any platform specific code, which
+/// makes it usable on targets like `wasm32-unknown-unknown` where Argon2 or scrypt
+/// are too memory hungry. Salts are generated with `OsRng`, so on that target the
+/// `getrandom` crate needs its `js` feature enabled by the final binary.
+///
+/// The static `PasswordHasher` functions use the recommended number of rounds
+/// (600,000). Use `Pbkdf2PasswordHasher::with_rounds` to hash with another number.
+///
+/// ```rust
+/// # use crate::svc_std::password_hasher::pbkdf2::Pbkdf2PasswordHasher;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hasher = Pbkdf2PasswordHasher::with_rounds(1000)?;
+///     let hash = hasher.hash_password("mmholAhsbC123*")?;
+///     assert!(hash.starts_with("$pbkdf2-sha256$i=1000,l=32$"));
+///     assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pbkdf2PasswordHasher {
+    rounds: u32,
+}
+
+impl Default for Pbkdf2PasswordHasher {
+    fn default() -> Self {
+        Self {
+            rounds: Params::RECOMMENDED_ROUNDS as u32,
+        }
+    }
+}
+
+impl Pbkdf2PasswordHasher {
+    /// Initializes a new hasher with the provided number of rounds.
+    ///
+    /// Returns `Error::HashingError` if the number of rounds is zero.
+    pub fn with_rounds(rounds: u32) -> Result<Self, Error> {
+        if rounds == 0 {
+            return Err(Error::HashingError(
+                "number of rounds must be positive".to_string(),
+            ));
+        }
+        Ok(Self { rounds })
+    }
+
+    /// Returns the number of rounds used for hashing.
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+
+    fn params(&self) -> Params {
+        Params {
+            rounds: self.rounds,
+            ..Params::default()
+        }
+    }
+
+    /// Returns the hash for the provided input using the configured number of rounds.
+    pub fn hash_password(&self, input: &str) -> Result<String, Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        Ok(Pbkdf2
+            .hash_password_customized(input.as_bytes(), None, None, self.params(), &salt)?
+            .to_string())
+    }
+
+    /// Confirms whether the provided password matches for the provided hash.
+    ///
+    /// The algorithm and rounds encoded in the hash take precedence over the configured ones.
+    pub fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        let parsed_hash = parse_phc(hash)?;
+        Pbkdf2
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .map_err(verification_error)
+    }
+
+    /// Confirms whether the provided hash uses another algorithm than PBKDF2-HMAC-SHA256,
+    /// or fewer rounds than configured.
+    pub fn check_rehash(&self, hash: &str) -> Result<bool, Error> {
+        let parsed_hash = parse_phc(hash)?;
+        let algorithm =
+            Algorithm::try_from(parsed_hash.algorithm).map_err(|_| Error::MalformedHash)?;
+        if algorithm != Algorithm::Pbkdf2Sha256 {
+            return Ok(true);
+        }
+        let params = Params::try_from(&parsed_hash).map_err(|_| Error::MalformedHash)?;
+        Ok(params.rounds < self.rounds)
+    }
+}
+
+impl PasswordHasher for Pbkdf2PasswordHasher {
+    fn hash(input: &str) -> Result<String, Error> {
+        Self::default().hash_password(input)
+    }
+
+    fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
+        Self::default().verify_password(password, hash)
+    }
+
+    fn needs_rehash(hash: &str) -> Result<bool, Error> {
+        Self::default().check_rehash(hash)
+    }
+}
+
+impl ConfiguredPasswordHasher for Pbkdf2PasswordHasher {
+    fn hash_password(&self, input: &str) -> Result<String, Error> {
+        Pbkdf2PasswordHasher::hash_password(self, input)
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        Pbkdf2PasswordHasher::verify_password(self, password, hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_works() {
+        let hasher = Pbkdf2PasswordHasher::with_rounds(1000).unwrap();
+        let hash = hasher.hash_password("mmholAhsbC123*").unwrap();
+        assert!(hash.starts_with("$pbkdf2-sha256$i=1000,l=32$"));
+        assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+        assert!(Pbkdf2PasswordHasher::confirm_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            hasher.verify_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(hasher.check_rehash(&hash), Ok(false));
+        assert_eq!(Pbkdf2PasswordHasher::needs_rehash(&hash), Ok(true));
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        assert!(matches!(
+            Pbkdf2PasswordHasher::with_rounds(0),
+            Err(Error::HashingError(_))
+        ));
+        assert!(matches!(
+            Pbkdf2PasswordHasher::confirm_password("mmholAhsbC123*", "garbage"),
+            Err(Error::MalformedHash)
+        ));
+    }
+}
@@ -0,0 +1,240 @@
+use crate::traits::{password_hasher::Error, PasswordHasher};
+use std::{
+    marker::PhantomData,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread::JoinHandle,
+};
+use zeroize::Zeroizing;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A bounded pool of threads dedicated to password hashing, e.g. to keep the
+/// worker threads of a sync service available while Argon2 is running.
+///
+/// At most `size` hashes run in parallel, further calls wait in a queue. Calls block
+/// the calling thread until their result is available. A panicking hasher fails
+/// its call only, the thread keeps serving. Dropping the pool finishes the queued
+/// work and joins its threads.
+///
+/// ```rust
+/// # use crate::svc_std::password_hasher::{argon2::Argon2PasswordHasher, pool::HasherPool};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let pool = HasherPool::<Argon2PasswordHasher>::new(2)?;
+///     let hash = pool.hash("mmholAhsbC123*")?;
+///     assert!(pool.confirm("mmholAhsbC123*", &hash).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+pub struct HasherPool<P: PasswordHasher> {
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+    phantom: PhantomData<fn() -> P>,
+}
+
+impl<P: PasswordHasher + 'static> HasherPool<P> {
+    /// Initializes a new pool with the provided number of threads.
+    ///
+    /// Returns `Error::HashingError` if the size is 0 or a thread couldn't be spawned.
+    pub fn new(size: usize) -> Result<Self, Error> {
+        if size == 0 {
+            return Err(Error::HashingError(
+                "hasher pool size must be at least 1".to_string(),
+            ));
+        }
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (0..size)
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                std::thread::Builder::new()
+                    .name(format!("hasher-pool-{i}"))
+                    .spawn(move || loop {
+                        // The lock is released before running the job.
+                        let job = match receiver.lock() {
+                            Ok(receiver) => receiver.recv(),
+                            Err(_) => return,
+                        };
+                        match job {
+                            // The caller is notified by the dropped reply channel.
+                            Ok(job) => {
+                                let _ = catch_unwind(AssertUnwindSafe(job));
+                            }
+                            Err(_) => return,
+                        }
+                    })
+                    .map_err(|e| Error::HashingError(e.to_string()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            sender: Some(sender),
+            workers,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of threads, which bounds the number of parallel hashes.
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Returns the hash for the provided input, computed on the pool.
+    ///
+    /// Returns `Error::HashingError` if the hasher failed or panicked, or the pool is
+    /// unavailable.
+    pub fn hash(&self, input: &str) -> Result<String, Error> {
+        let input = Zeroizing::new(input.to_string());
+        self.run(move || P::hash(&input))
+    }
+
+    /// Confirms whether the provided password matches for the provided hash,
+    /// verified on the pool.
+    ///
+    /// Returns the errors of `PasswordHasher::confirm_password`, or
+    /// `Error::HashingError` if the pool is unavailable.
+    pub fn confirm(&self, password: &str, hash: &str) -> Result<(), Error> {
+        let password = Zeroizing::new(password.to_string());
+        let hash = hash.to_string();
+        self.run(move || P::confirm_password(&password, &hash))
+    }
+
+    /// Submits the provided work and blocks until its result is available.
+    fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce() -> Result<R, Error> + Send + 'static,
+    ) -> Result<R, Error> {
+        let unavailable = || Error::HashingError("hasher pool is unavailable".to_string());
+        let (sender, receiver) = mpsc::channel();
+        self.sender
+            .as_ref()
+            .ok_or_else(unavailable)?
+            .send(Box::new(move || {
+                // The caller may have stopped waiting, so the result can be discarded.
+                let _ = sender.send(f());
+            }))
+            .map_err(|_| unavailable())?;
+        receiver
+            .recv()
+            .map_err(|_| Error::HashingError("password hasher panicked".to_string()))?
+    }
+}
+
+impl<P: PasswordHasher> Drop for HasherPool<P> {
+    fn drop(&mut self) {
+        // Closing the channel stops the workers once the queue is drained.
+        self.sender.take();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::password_hasher::argon2::Argon2PasswordHasher;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    static RUNNING: AtomicUsize = AtomicUsize::new(0);
+    static MAX_RUNNING: AtomicUsize = AtomicUsize::new(0);
+
+    /// Hasher recording how many hashes run in parallel.
+    struct SlowHasher;
+    impl PasswordHasher for SlowHasher {
+        fn hash(input: &str) -> Result<String, Error> {
+            let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+            MAX_RUNNING.fetch_max(running, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(20));
+            RUNNING.fetch_sub(1, Ordering::SeqCst);
+            Ok(input.chars().rev().collect())
+        }
+
+        fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
+            match Self::hash(password)? == hash {
+                true => Ok(()),
+                false => Err(Error::InvalidPassword),
+            }
+        }
+
+        fn needs_rehash(_hash: &str) -> Result<bool, Error> {
+            Ok(false)
+        }
+    }
+
+    /// Hasher panicking on the input `panic`.
+    struct PanickingHasher;
+    impl PasswordHasher for PanickingHasher {
+        fn hash(input: &str) -> Result<String, Error> {
+            assert_ne!(input, "panic");
+            Ok(input.to_string())
+        }
+
+        fn confirm_password(_password: &str, _hash: &str) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn needs_rehash(_hash: &str) -> Result<bool, Error> {
+            Ok(false)
+        }
+    }
+
+    #[test]
+    fn pool_survives_panics() {
+        let pool = HasherPool::<PanickingHasher>::new(1).unwrap();
+        for _ in 0..3 {
+            assert!(matches!(pool.hash("panic"), Err(Error::HashingError(_))));
+        }
+        assert_eq!(pool.hash("password"), Ok("password".to_string()));
+    }
+
+    #[test]
+    fn pool_size_bounds_parallelism() {
+        let pool = HasherPool::<SlowHasher>::new(2).unwrap();
+        assert_eq!(pool.size(), 2);
+
+        std::thread::scope(|s| {
+            let handles: Vec<_> = (0..8)
+                .map(|i| {
+                    let pool = &pool;
+                    s.spawn(move || pool.hash(&format!("password{i}")))
+                })
+                .collect();
+            for (i, handle) in handles.into_iter().enumerate() {
+                let expected: String = format!("password{i}").chars().rev().collect();
+                assert_eq!(handle.join().unwrap(), Ok(expected));
+            }
+        });
+        assert_eq!(MAX_RUNNING.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn pool_hashes_concurrently() {
+        let pool = HasherPool::<Argon2PasswordHasher>::new(2).unwrap();
+        std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|i| {
+                    let pool = &pool;
+                    s.spawn(move || {
+                        let password = format!("mmholAhsbC123*{i}");
+                        let hash = pool.hash(&password).unwrap();
+                        assert!(pool.confirm(&password, &hash).is_ok());
+                        assert_eq!(pool.confirm("blabla", &hash), Err(Error::InvalidPassword));
+                    })
+                })
+                .collect();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+        });
+
+        assert!(matches!(
+            HasherPool::<Argon2PasswordHasher>::new(0),
+            Err(Error::HashingError(_))
+        ));
+    }
+}
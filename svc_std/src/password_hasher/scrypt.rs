@@ -0,0 +1,148 @@
+use super::{parse_phc, verification_error};
+use crate::traits::{password_hasher::Error, ConfiguredPasswordHasher, PasswordHasher};
+use scrypt::{
+    errors::InvalidParams,
+    password_hash::{
+        rand_core::OsRng, PasswordHasher as CorePasswordHasher, PasswordVerifier, SaltString,
+    },
+    Params, Scrypt,
+};
+
+impl From<InvalidParams> for Error {
+    fn from(value: InvalidParams) -> Self {
+        Self::HashingError(value.to_string())
+    }
+}
+
+/// Implementation of the PasswordHasher trait using scrypt.
+///
+/// The static `PasswordHasher` functions use the recommended scrypt parameters
+/// (log N = 17, r = 8, p = 1). Use `ScryptPasswordHasher::with_params` to hash
+/// with custom parameters.
+///
+/// ```rust
+/// # use crate::svc_std::password_hasher::scrypt::ScryptPasswordHasher;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hasher = ScryptPasswordHasher::with_params(10, 8, 1)?;
+///     let hash = hasher.hash_password("mmholAhsbC123*")?;
+///     assert!(hash.starts_with("$scrypt$ln=10,r=8,p=1$"));
+///     assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScryptPasswordHasher {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+impl Default for ScryptPasswordHasher {
+    fn default() -> Self {
+        Self {
+            log_n: Params::RECOMMENDED_LOG_N,
+            r: Params::RECOMMENDED_R,
+            p: Params::RECOMMENDED_P,
+        }
+    }
+}
+
+impl ScryptPasswordHasher {
+    /// Initializes a new hasher with the provided CPU/memory cost (as log2 of N),
+    /// block size and degree of parallelism.
+    ///
+    /// Returns `Error::HashingError` if the parameters are out of range.
+    pub fn with_params(log_n: u8, r: u32, p: u32) -> Result<Self, Error> {
+        Params::new(log_n, r, p, Params::RECOMMENDED_LEN)?;
+        Ok(Self { log_n, r, p })
+    }
+
+    fn params(&self) -> Result<Params, Error> {
+        Ok(Params::new(
+            self.log_n,
+            self.r,
+            self.p,
+            Params::RECOMMENDED_LEN,
+        )?)
+    }
+
+    /// Returns the hash for the provided input using the configured parameters.
+    pub fn hash_password(&self, input: &str) -> Result<String, Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        Ok(Scrypt
+            .hash_password_customized(input.as_bytes(), None, None, self.params()?, &salt)?
+            .to_string())
+    }
+
+    /// Confirms whether the provided password matches for the provided hash.
+    ///
+    /// The parameters encoded in the hash take precedence over the configured ones.
+    pub fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        let parsed_hash = parse_phc(hash)?;
+        Scrypt
+            .verify_password(password.as_bytes(), &parsed_hash)
+            .map_err(verification_error)
+    }
+
+    /// Confirms whether the provided hash uses any lower cost parameter than the configured ones.
+    pub fn check_rehash(&self, hash: &str) -> Result<bool, Error> {
+        let parsed_hash = parse_phc(hash)?;
+        let params = Params::try_from(&parsed_hash).map_err(|_| Error::MalformedHash)?;
+        Ok(params.log_n() < self.log_n || params.r() < self.r || params.p() < self.p)
+    }
+}
+
+impl PasswordHasher for ScryptPasswordHasher {
+    fn hash(input: &str) -> Result<String, Error> {
+        Self::default().hash_password(input)
+    }
+
+    fn confirm_password(password: &str, hash: &str) -> Result<(), Error> {
+        Self::default().verify_password(password, hash)
+    }
+
+    fn needs_rehash(hash: &str) -> Result<bool, Error> {
+        Self::default().check_rehash(hash)
+    }
+}
+
+impl ConfiguredPasswordHasher for ScryptPasswordHasher {
+    fn hash_password(&self, input: &str) -> Result<String, Error> {
+        ScryptPasswordHasher::hash_password(self, input)
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error> {
+        ScryptPasswordHasher::verify_password(self, password, hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_works() {
+        let hasher = ScryptPasswordHasher::with_params(10, 8, 1).unwrap();
+        let hash = hasher.hash_password("mmholAhsbC123*").unwrap();
+        assert!(hasher.verify_password("mmholAhsbC123*", &hash).is_ok());
+        assert!(ScryptPasswordHasher::confirm_password("mmholAhsbC123*", &hash).is_ok());
+        assert_eq!(
+            hasher.verify_password("blabla", &hash),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(hasher.check_rehash(&hash), Ok(false));
+        assert_eq!(ScryptPasswordHasher::needs_rehash(&hash), Ok(true));
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        assert!(matches!(
+            ScryptPasswordHasher::with_params(10, 0, 1),
+            Err(Error::HashingError(_))
+        ));
+        assert!(matches!(
+            ScryptPasswordHasher::confirm_password("mmholAhsbC123*", "garbage"),
+            Err(Error::MalformedHash)
+        ));
+    }
+}
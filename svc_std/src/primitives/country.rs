@@ -0,0 +1,138 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+
+/// Officially assigned ISO 3166-1 alpha-2 codes, sorted for binary search.
+const CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// A validatable ISO 3166-1 alpha-2 country code.
+///
+/// Codes are normalized to uppercase and must be officially assigned.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{CountryCode, Error, ValidationError};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let country = CountryCode::new("nl")?;
+///     assert_eq!(country.as_str(), "NL");
+///     assert_eq!(CountryCode::new("XX"), Err(Error::Validation(ValidationError::CountryCode)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CountryCode(String);
+
+impl Validatable<Error> for CountryCode {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        if CODES.binary_search(&self.0.as_str()).is_err() {
+            return Err(ValidationError::CountryCode.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for CountryCode {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for CountryCode {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        CountryCode::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CountryCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CountryCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        CountryCode::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl CountryCode {
+    /// Initializes a new country code instance, normalized to uppercase.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(value.to_ascii_uppercase());
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the country code as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn country_codes_are_sorted() {
+        assert_eq!(CODES.len(), 249);
+        assert!(CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn country_code_validation_works() {
+        let us = CountryCode::new("us").unwrap();
+        assert_eq!(us, CountryCode::new("US").unwrap());
+        assert_eq!(us.to_string(), "US");
+        assert_eq!(CountryCode::try_from("Us").unwrap(), us);
+
+        for value in ["", "U", "USA", "XX", "ZZ", "u1", " US"] {
+            assert_eq!(
+                CountryCode::new(value),
+                Err(Error::Validation(ValidationError::CountryCode)),
+                "{value}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn country_code_serde_works() {
+        let country = CountryCode::new("de").unwrap();
+        let json = serde_json::to_string(&country).unwrap();
+        assert_eq!(json, r#""DE""#);
+        assert_eq!(
+            serde_json::from_str::<CountryCode>(r#""de""#).unwrap(),
+            country
+        );
+        assert!(serde_json::from_str::<CountryCode>(r#""XX""#).is_err());
+    }
+}
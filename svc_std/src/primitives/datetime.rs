@@ -1,9 +1,32 @@
-use std::time::SystemTime;
+use super::{error::ValidationError, Error};
+use std::time::{Duration, SystemTime};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
 /// A datetime field based on SystemTime.
+///
+/// With the `serde` feature datetimes are serialized as RFC 3339 strings in UTC.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct DateTime(SystemTime);
 
+/// Fails for years outside of the range supported by RFC 3339 (0 to 9999).
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateTime {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = self
+            .rfc3339()
+            .ok_or_else(|| serde::ser::Error::custom("RFC 3339 supports years 0 to 9999"))?;
+        serializer.serialize_str(&value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DateTime {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        DateTime::from_rfc3339(&value).map_err(serde::de::Error::custom)
+    }
+}
+
 impl std::default::Default for DateTime {
     fn default() -> Self {
         Self(SystemTime::now())
@@ -24,8 +47,312 @@ impl std::convert::AsRef<SystemTime> for DateTime {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for DateTime {
+    fn from(value: chrono::DateTime<chrono::Utc>) -> Self {
+        Self(value.into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<DateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(value: DateTime) -> Self {
+        value.0.into()
+    }
+}
+
+/// Describes the datetime as an RFC 3339 (`date-time`) string.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for DateTime {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "DateTime".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "format": "date-time",
+        })
+    }
+}
+
+/// Parses an RFC 3339 formatted string, see `DateTime::from_rfc3339`.
+impl std::str::FromStr for DateTime {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        DateTime::from_rfc3339(value)
+    }
+}
+
+/// Formats the DateTime as RFC 3339 in UTC, e.g. `2023-11-14T22:13:20Z`.
+///
+/// Times that can't be represented in RFC 3339 fall back to the debug representation.
+impl std::fmt::Display for DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.rfc3339() {
+            Some(value) => write!(f, "{value}"),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
+}
+
 impl DateTime {
     pub fn now() -> Self {
         Self::default()
     }
+
+    /// Returns the time the provided duration later, or None if it can't be represented.
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    /// Returns the time the provided duration earlier, or None if it can't be represented.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration).map(Self)
+    }
+
+    /// Returns the duration elapsed since this time.
+    ///
+    /// Returns a zero duration if this time lies in the future.
+    pub fn elapsed(&self) -> Duration {
+        self.0.elapsed().unwrap_or_default()
+    }
+
+    /// Returns the duration since the provided earlier time.
+    ///
+    /// Returns None if the provided time is later than this time.
+    pub fn duration_since(&self, earlier: &DateTime) -> Option<Duration> {
+        self.0.duration_since(earlier.0).ok()
+    }
+
+    /// Initializes a new DateTime from seconds since the Unix epoch.
+    ///
+    /// Negative values represent times before the epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time can't be represented by the platform's SystemTime.
+    pub fn from_unix_secs(secs: i64) -> Self {
+        Self::from_unix_duration(secs.is_negative(), Duration::from_secs(secs.unsigned_abs()))
+    }
+
+    /// Initializes a new DateTime from milliseconds since the Unix epoch.
+    ///
+    /// Negative values represent times before the epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time can't be represented by the platform's SystemTime.
+    pub fn from_unix_millis(millis: i64) -> Self {
+        Self::from_unix_duration(
+            millis.is_negative(),
+            Duration::from_millis(millis.unsigned_abs()),
+        )
+    }
+
+    /// Returns the number of whole seconds since the Unix epoch.
+    ///
+    /// Times before the epoch are negative and rounded down, like Unix timestamps.
+    pub fn unix_secs(&self) -> i64 {
+        self.unix_nanos().div_euclid(1_000_000_000) as i64
+    }
+
+    /// Returns the number of whole milliseconds since the Unix epoch.
+    ///
+    /// Times before the epoch are negative and rounded down, like Unix timestamps.
+    pub fn unix_millis(&self) -> i64 {
+        self.unix_nanos().div_euclid(1_000_000) as i64
+    }
+
+    fn from_unix_duration(before_epoch: bool, duration: Duration) -> Self {
+        let datetime = match before_epoch {
+            true => SystemTime::UNIX_EPOCH.checked_sub(duration),
+            false => SystemTime::UNIX_EPOCH.checked_add(duration),
+        };
+        Self(datetime.expect("time out of range for SystemTime"))
+    }
+
+    fn unix_nanos(&self) -> i128 {
+        match self.0.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        }
+    }
+
+    /// Parses an RFC 3339 formatted string, e.g. `2023-11-14T22:13:20Z`.
+    ///
+    /// Returns a validation error if the input isn't a valid RFC 3339 string.
+    pub fn from_rfc3339(value: &str) -> Result<Self, Error> {
+        let datetime =
+            OffsetDateTime::parse(value, &Rfc3339).map_err(|_| ValidationError::DateTime)?;
+        Ok(Self(datetime.into()))
+    }
+
+    /// Returns the RFC 3339 representation in UTC, e.g. `2023-11-14T22:13:20Z`.
+    ///
+    /// Fractional seconds are only included when present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year is outside of the range supported by RFC 3339 (0 to 9999).
+    pub fn to_rfc3339(&self) -> String {
+        self.rfc3339().expect("RFC 3339 supports years 0 to 9999")
+    }
+
+    fn rfc3339(&self) -> Option<String> {
+        OffsetDateTime::from_unix_timestamp_nanos(self.unix_nanos())
+            .ok()?
+            .format(&Rfc3339)
+            .ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn datetime_parse_works() {
+        let datetime: DateTime = "2023-11-14T22:13:20Z".parse().unwrap();
+        assert_eq!(datetime, DateTime::from_unix_secs(1_700_000_000));
+        assert_eq!(datetime.to_string().parse(), Ok(datetime));
+        assert_eq!(
+            "2023-11-14".parse::<DateTime>(),
+            Err(Error::Validation(ValidationError::DateTime))
+        );
+    }
+
+    #[test]
+    fn rfc3339_works() {
+        let datetime = DateTime::from_rfc3339("2023-11-14T22:13:20Z").unwrap();
+        assert_eq!(
+            *datetime,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+        assert_eq!(datetime.to_rfc3339(), "2023-11-14T22:13:20Z");
+
+        // Ensure that offsets are normalized to UTC and fractions are preserved.
+        let datetime = DateTime::from_rfc3339("2023-11-15T00:13:20.5+02:00").unwrap();
+        assert_eq!(datetime.to_rfc3339(), "2023-11-14T22:13:20.5Z");
+
+        for value in [
+            "",
+            "2023-11-14",
+            "2023-11-14 22:13:20",
+            "2023-13-14T22:13:20Z",
+        ] {
+            assert_eq!(
+                DateTime::from_rfc3339(value),
+                Err(Error::Validation(ValidationError::DateTime))
+            );
+        }
+    }
+
+    #[test]
+    fn display_works() {
+        let datetime = DateTime::from_unix_secs(1_700_000_000);
+        assert_eq!(datetime.to_string(), "2023-11-14T22:13:20Z");
+        assert_eq!(
+            format!("created at {datetime}"),
+            "created at 2023-11-14T22:13:20Z"
+        );
+
+        let datetime = DateTime::from_unix_secs(300_000_000_000);
+        assert!(datetime.to_string().starts_with("SystemTime"));
+    }
+
+    #[test]
+    fn arithmetic_works() {
+        let datetime = DateTime::from_unix_secs(1_700_000_000);
+        let later = datetime.checked_add(Duration::from_secs(15 * 60)).unwrap();
+        assert_eq!(later.to_rfc3339(), "2023-11-14T22:28:20Z");
+        assert_eq!(
+            later.checked_sub(Duration::from_secs(15 * 60)),
+            Some(datetime)
+        );
+
+        assert_eq!(
+            later.duration_since(&datetime),
+            Some(Duration::from_secs(15 * 60))
+        );
+        assert_eq!(datetime.duration_since(&later), None);
+        assert_eq!(datetime.duration_since(&datetime), Some(Duration::ZERO));
+
+        assert!(datetime.elapsed() > Duration::ZERO);
+        let future = DateTime::now()
+            .checked_add(Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(future.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn unix_conversion_works() {
+        let epoch = DateTime::from_unix_secs(0);
+        assert_eq!(*epoch, SystemTime::UNIX_EPOCH);
+        assert_eq!(epoch.unix_secs(), 0);
+        assert_eq!(epoch.unix_millis(), 0);
+
+        let datetime = DateTime::from_unix_millis(1_700_000_000_123);
+        assert_eq!(datetime.to_rfc3339(), "2023-11-14T22:13:20.123Z");
+        assert_eq!(datetime.unix_secs(), 1_700_000_000);
+        assert_eq!(datetime.unix_millis(), 1_700_000_000_123);
+        assert_eq!(
+            DateTime::from_unix_secs(1_700_000_000).unix_secs(),
+            1_700_000_000
+        );
+
+        let datetime = DateTime::from_unix_secs(-86_400);
+        assert_eq!(datetime.to_rfc3339(), "1969-12-31T00:00:00Z");
+        assert_eq!(datetime.unix_secs(), -86_400);
+        assert_eq!(datetime.unix_millis(), -86_400_000);
+
+        // Ensure that fractional times before the epoch round down.
+        let datetime = DateTime::from_unix_millis(-1_500);
+        assert_eq!(datetime.unix_secs(), -2);
+        assert_eq!(datetime.unix_millis(), -1_500);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversion_works() {
+        let now = DateTime::now();
+        let chrono: chrono::DateTime<chrono::Utc> = now.into();
+        assert_eq!(DateTime::from(chrono), now);
+
+        let chrono = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let datetime = DateTime::from(chrono);
+        assert_eq!(datetime.to_rfc3339(), "2023-11-14T22:13:20Z");
+        assert_eq!(chrono::DateTime::<chrono::Utc>::from(datetime), chrono);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn datetime_schema_works() {
+        let schema = schemars::schema_for!(DateTime);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("format").unwrap(), "date-time");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn datetime_serde_works() {
+        let now = DateTime::now();
+        let json = serde_json::to_string(&now).unwrap();
+        assert_eq!(serde_json::from_str::<DateTime>(&json).unwrap(), now);
+
+        let datetime = DateTime::from_unix_secs(1_700_000_000);
+        let json = serde_json::to_string(&datetime).unwrap();
+        assert_eq!(json, r#""2023-11-14T22:13:20Z""#);
+        assert_eq!(serde_json::from_str::<DateTime>(&json).unwrap(), datetime);
+
+        // Ensure that datetimes before the epoch are supported.
+        let datetime = DateTime::from_unix_secs(-86_400);
+        let json = serde_json::to_string(&datetime).unwrap();
+        assert_eq!(json, r#""1969-12-31T00:00:00Z""#);
+        assert_eq!(serde_json::from_str::<DateTime>(&json).unwrap(), datetime);
+
+        assert!(serde_json::from_str::<DateTime>(r#""blabla""#).is_err());
+        assert!(serde_json::from_str::<DateTime>("1700000000").is_err());
+    }
 }
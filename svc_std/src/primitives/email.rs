@@ -1,9 +1,83 @@
 use super::error::{Error, ValidationError};
-use crate::traits::validatable::Validatable;
+use crate::traits::validatable::{Validatable, ValidatableAll};
 use fancy_regex::Regex;
+use std::{collections::HashSet, sync::OnceLock};
+use subtle::ConstantTimeEq;
+
+/// Pattern for the characters allowed in the local part of an email address.
+#[cfg(not(feature = "idn"))]
+const LOCAL_PART_CHARS: &str = "a-zA-Z0-9_+";
+
+/// Pattern for the characters allowed in the local part of an email address.
+///
+/// With the `idn` feature any unicode letter or number is allowed.
+#[cfg(feature = "idn")]
+const LOCAL_PART_CHARS: &str = r"\p{L}\p{N}_+";
+
+/// Maximum length of an email address in octets, as per RFC 5321.
+pub const MAX_LEN: usize = 254;
+
+/// Maximum length of the local part of an email address in octets, as per RFC 5321.
+pub const MAX_LOCAL_PART_LEN: usize = 64;
+
+/// Returns the email regex, which is compiled once on first use.
+///
+/// The domain is matched case-insensitively, so unnormalized input can be checked.
+/// The TLD may be up to 63 characters, the DNS label limit, or an IDN TLD in punycode.
+fn email_regex() -> &'static Regex {
+    static EMAIL_REGEX: OnceLock<Regex> = OnceLock::new();
+    EMAIL_REGEX.get_or_init(|| {
+        Regex::new(&format!(
+            r"^([{c}]([{c}.]*[{c}])?)@(?i:([a-z0-9]+((\-+|\.)[a-z0-9]+)*\.([a-z]{{2,63}}|xn--[a-z0-9]+)))$",
+            c = LOCAL_PART_CHARS
+        ))
+        .expect("email pattern is valid")
+    })
+}
+
+/// Confirms whether the provided value is a valid email, without constructing an `Email`.
+///
+/// Accepts the same values as `Email::new`. Only unicode input with the `idn`
+/// feature needs to be converted, and therefore allocates.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::email::is_valid_email;
+/// assert!(is_valid_email("John.Doe@Example.com"));
+/// assert!(!is_valid_email("not an email"));
+/// ```
+pub fn is_valid_email(value: &str) -> bool {
+    #[cfg(feature = "idn")]
+    if !value.is_ascii() {
+        return Email::new(value).is_ok();
+    }
+    is_valid(value)
+}
+
+/// Confirms whether the provided value satisfies the length limits and the email regex.
+fn is_valid(value: &str) -> bool {
+    is_within_limits(value) && email_regex().is_match(value).unwrap_or(false)
+}
+
+/// Confirms whether the provided value satisfies the length limits of RFC 5321.
+fn is_within_limits(value: &str) -> bool {
+    let local_part = value
+        .rsplit_once('@')
+        .map_or("", |(local_part, _)| local_part);
+    value.len() <= MAX_LEN && local_part.len() <= MAX_LOCAL_PART_LEN
+}
 
 /// A validatable email field.
 ///
+/// Mixed case is accepted. The domain is case-insensitive and therefore normalized
+/// to lowercase on storage, while the case of the local part is preserved.
+/// As a result emails differing only in the case of their domain are equal.
+///
+/// With the `idn` feature unicode domains are accepted and stored in their
+/// ASCII compatible (punycode) encoding, e.g. `müller.de` becomes `xn--mller-kva.de`.
+///
+/// To keep addresses out of logs `Debug` masks the local part, e.g. `j***@example.com`,
+/// while `Display` and `as_str` give the full value.
+///
 /// ```rust
 /// # use crate::svc_std::{traits::Validatable, primitives::{Email, Error, ValidationError}};
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -12,24 +86,28 @@ use fancy_regex::Regex;
 ///     assert_eq!(Email::new("not an email"), Err(Error::Validation(ValidationError::Email)));
 ///
 ///     let jane_email: Email = "jane.doe@example.com".try_into()?;
+///     assert_eq!(Email::new("Jane.Doe@Example.COM")?.to_string(), "Jane.Doe@example.com");
 /// #    Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct Email(String);
 
 impl Validatable<Error> for Email {
     fn validate(&self) -> crate::traits::validatable::Result<Error> {
-        let email_regex = Regex::new(
-            r"^([a-z0-9_+]([a-z0-9_+.]*[a-z0-9_+])?)@([a-z0-9]+([\-\.]{1}[a-z0-9]+)*\.[a-z]{2,6})",
-        )?;
-        if !email_regex.is_match(&self.0).unwrap_or(false) {
+        if !is_valid(&self.0) {
             return Err(ValidationError::Email.into());
         }
         Ok(())
     }
 }
 
+impl ValidatableAll<Error> for Email {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
 impl TryFrom<&str> for Email {
     type Error = Error;
 
@@ -38,6 +116,105 @@ impl TryFrom<&str> for Email {
     }
 }
 
+impl std::str::FromStr for Email {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Email::new(value)
+    }
+}
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<str> for Email {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Email {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Email {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Email::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Describes the email as a string in the `email` format.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Email {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Email".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "format": "email",
+            "maxLength": MAX_LEN,
+        })
+    }
+}
+
+/// Stores the email as text, e.g. `text` on Postgres.
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database> sqlx::Type<DB> for Email
+where
+    str: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for Email
+where
+    String: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+}
+
+/// Validates the stored value, invalid emails surface as decode errors.
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for Email
+where
+    &'r str: sqlx::Decode<'r, DB>,
+{
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let value = <&str as sqlx::Decode<DB>>::decode(value)?;
+        Ok(Email::new(value)?)
+    }
+}
+
+impl std::fmt::Debug for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Email").field(&self.redacted()).finish()
+    }
+}
+
 impl std::fmt::Display for Email {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -49,10 +226,140 @@ impl Email {
     ///
     /// Returns a validation error if validation of the provided value fails.
     pub fn new(value: &str) -> Result<Self, Error> {
-        let v = Self(value.to_string());
+        let v = Self(Self::normalize(value));
         v.validate()?;
         Ok(v)
     }
+
+    /// Initializes a new email instance, validated by the provided predicate instead
+    /// of the bundled regex, e.g. to be stricter or more lenient.
+    ///
+    /// The predicate receives the normalized value, the length limits always apply.
+    /// Compose with `is_valid_email` to extend the default rules. Note that
+    /// `validate`, deserialization and decoding apply the default rules.
+    ///
+    /// Returns `ValidationError::Email` if the predicate or the length limits reject the value.
+    ///
+    /// ```rust
+    /// # use crate::svc_std::primitives::{email::is_valid_email, Email};
+    /// let no_test_domains = |value: &str| is_valid_email(value) && !value.ends_with(".test");
+    /// assert!(Email::new_with_validator("john.doe@example.com", &no_test_domains).is_ok());
+    /// assert!(Email::new_with_validator("john.doe@example.test", &no_test_domains).is_err());
+    /// ```
+    pub fn new_with_validator(
+        value: &str,
+        validator: &dyn Fn(&str) -> bool,
+    ) -> Result<Self, Error> {
+        let v = Self(Self::normalize(value));
+        if !is_within_limits(&v.0) || !validator(&v.0) {
+            return Err(ValidationError::Email.into());
+        }
+        Ok(v)
+    }
+
+    /// Initializes a new email instance, rejecting domains in the provided blocklist.
+    ///
+    /// The blocklist is supplied by the caller to keep it current and is expected
+    /// to contain lowercase domains.
+    ///
+    /// Returns `ValidationError::DisposableEmail` if the domain is blocked or
+    /// another validation error if validation of the provided value fails.
+    pub fn new_checked(value: &str, blocklist: &HashSet<String>) -> Result<Self, Error> {
+        let v = Self::new(value)?;
+        if blocklist.contains(v.domain()) {
+            return Err(ValidationError::DisposableEmail.into());
+        }
+        Ok(v)
+    }
+
+    /// Initializes a new email instance, confirming that the domain can receive mail.
+    ///
+    /// The domain must have at least one MX record or, as a fallback, an address record.
+    ///
+    /// Returns `ValidationError::UnresolvableDomain` if the domain has neither,
+    /// `Error::DnsLookupError` if the lookup failed or another validation error
+    /// if validation of the provided value fails.
+    #[cfg(feature = "dns")]
+    pub async fn new_with_mx_check(
+        value: &str,
+        resolver: &impl crate::dns::DomainResolver,
+    ) -> Result<Self, Error> {
+        let v = Self::new(value)?;
+        if !crate::dns::accepts_mail(v.domain(), resolver).await? {
+            return Err(ValidationError::UnresolvableDomain.into());
+        }
+        Ok(v)
+    }
+
+    /// Returns the email as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Confirms whether the emails are equal in constant time, e.g. to avoid
+    /// leaking which addresses exist during password resets.
+    ///
+    /// Only the lengths of the emails may influence the duration.
+    pub fn ct_eq(&self, other: &Email) -> bool {
+        self.0.as_bytes().ct_eq(other.0.as_bytes()).into()
+    }
+
+    /// Returns the local part of the email, before the last `@`.
+    pub fn local_part(&self) -> &str {
+        self.0
+            .rsplit_once('@')
+            .map_or("", |(local_part, _)| local_part)
+    }
+
+    /// Returns the email with all but the first character of the local part masked,
+    /// e.g. `j***@example.com`, which is safe to log.
+    pub fn redacted(&self) -> String {
+        let first = self.local_part().chars().next().unwrap_or_default();
+        format!("{first}***@{}", self.domain())
+    }
+
+    /// Returns the domain of the email, after the last `@`.
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    /// Returns the canonical form of the email, without a plus-address tag.
+    ///
+    /// Strips everything from the first `+` up to the `@` in the local part, so
+    /// `john+news@example.com` becomes `john@example.com`. Useful for uniqueness
+    /// checks, the email itself is left untouched. Emails that wouldn't be valid
+    /// after stripping are returned as-is.
+    pub fn canonical(&self) -> Email {
+        match self.local_part().split_once('+') {
+            Some((base, _)) if !base.is_empty() => {
+                Self::new(&format!("{base}@{}", self.domain())).unwrap_or_else(|_| self.clone())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the provided value with the domain converted to lowercase.
+    fn normalize(value: &str) -> String {
+        match value.rsplit_once('@') {
+            Some((local_part, domain)) => {
+                format!("{local_part}@{}", Self::normalize_domain(domain))
+            }
+            None => value.to_string(),
+        }
+    }
+
+    #[cfg(not(feature = "idn"))]
+    fn normalize_domain(domain: &str) -> String {
+        domain.to_lowercase()
+    }
+
+    /// Converts the domain to its ASCII compatible encoding.
+    ///
+    /// Domains that can't be converted are only lowercased, to fail validation.
+    #[cfg(feature = "idn")]
+    fn normalize_domain(domain: &str) -> String {
+        idna::domain_to_ascii(domain).unwrap_or_else(|_| domain.to_lowercase())
+    }
 }
 
 #[cfg(test)]
@@ -79,4 +386,361 @@ mod tests {
             Err(Error::Validation(ValidationError::Email))
         );
     }
+
+    #[test]
+    fn email_tld_validation_works() {
+        for value in [
+            "john.doe@example.museum",
+            "john.doe@example.technology",
+            "john.doe@example.international",
+            "john.doe@example.xn--p1ai",
+            &format!("john.doe@example.{}", "a".repeat(63)),
+        ] {
+            assert!(Email::new(value).is_ok(), "{value}");
+        }
+
+        for value in [
+            "a@b",
+            "john.doe@localhost",
+            "john.doe@example.c",
+            "john.doe@example.com1",
+            "john.doe@example.com!",
+            &format!("john.doe@example.{}", "a".repeat(64)),
+        ] {
+            assert_eq!(
+                Email::new(value),
+                Err(Error::Validation(ValidationError::Email)),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_valid_email_works() {
+        let local_part = "a".repeat(MAX_LOCAL_PART_LEN);
+        let domain = "b".repeat(MAX_LEN - MAX_LOCAL_PART_LEN - 5);
+        let cases = [
+            "john.doe@example.com".to_string(),
+            "John.Doe@Example.COM".to_string(),
+            "john.doe+newsletter@mail.example.com".to_string(),
+            "a".to_string(),
+            "a@".to_string(),
+            "example.com".to_string(),
+            "a@.com".to_string(),
+            format!("{local_part}@example.com"),
+            format!("{local_part}a@example.com"),
+            format!("{local_part}@{domain}.com"),
+            format!("{local_part}@{domain}b.com"),
+        ];
+        for value in cases {
+            assert_eq!(
+                is_valid_email(&value),
+                Email::new(&value).is_ok(),
+                "{value}"
+            );
+        }
+        assert!(is_valid_email("John.Doe@Example.COM"));
+        assert!(!is_valid_email("a@.com"));
+    }
+
+    #[test]
+    fn email_regex_is_compiled_once() {
+        assert!(std::ptr::eq(email_regex(), email_regex()));
+        for _ in 0..3 {
+            assert!(Email::new("john.doe@example.com").is_ok());
+            assert!(Email::new("john.doe").is_err());
+        }
+    }
+
+    #[test]
+    fn email_case_normalization_works() {
+        let email = Email::new("John.Doe@Example.COM").unwrap();
+        assert_eq!(email.to_string(), "John.Doe@example.com");
+        assert_eq!(email, Email::new("John.Doe@example.com").unwrap());
+        assert_ne!(email, Email::new("john.doe@example.com").unwrap());
+    }
+
+    #[test]
+    fn email_parts_work() {
+        let email = Email::new("john.doe@example.com").unwrap();
+        assert_eq!(email.local_part(), "john.doe");
+        assert_eq!(email.domain(), "example.com");
+
+        let email = Email::new("john.doe+newsletter@mail.example.com").unwrap();
+        assert_eq!(email.local_part(), "john.doe+newsletter");
+        assert_eq!(email.domain(), "mail.example.com");
+    }
+
+    #[test]
+    fn email_debug_is_redacted() {
+        let email = Email::new("john.doe@example.com").unwrap();
+        assert_eq!(email.redacted(), "j***@example.com");
+        assert_eq!(format!("{email:?}"), r#"Email("j***@example.com")"#);
+        assert_eq!(email.to_string(), "john.doe@example.com");
+
+        // Ensure that the address is masked when nested, e.g. in a user.
+        assert!(!format!("{:?}", Some(email)).contains("john.doe"));
+    }
+
+    #[test]
+    fn email_parse_works() {
+        let email: Email = "John.Doe@Example.com".parse().unwrap();
+        assert_eq!(email.as_str(), "John.Doe@example.com");
+        assert_eq!(
+            "john.doe".parse::<Email>(),
+            Err(Error::Validation(ValidationError::Email))
+        );
+    }
+
+    #[test]
+    fn email_conversion_works() {
+        let email = Email::new("John.Doe@Example.com").unwrap();
+        assert_eq!(email.as_str(), "John.Doe@example.com");
+        assert_eq!(email.as_ref(), "John.Doe@example.com");
+
+        fn takes_str(value: impl AsRef<str>) -> usize {
+            value.as_ref().len()
+        }
+        assert_eq!(takes_str(&email), 20);
+        assert_eq!(String::from(email), "John.Doe@example.com");
+    }
+
+    #[test]
+    fn email_ct_eq_works() {
+        let john = Email::new("john.doe@example.com").unwrap();
+        for other in [
+            "john.doe@Example.com",
+            "jane.doe@example.com",
+            "john@example.com",
+        ] {
+            let other = Email::new(other).unwrap();
+            assert_eq!(john.ct_eq(&other), john == other);
+        }
+    }
+
+    #[test]
+    fn email_canonical_works() {
+        let email = Email::new("a+foo@x.com").unwrap();
+        assert_eq!(email.canonical(), Email::new("a@x.com").unwrap());
+        assert_eq!(email.to_string(), "a+foo@x.com");
+
+        let email = Email::new("a@x.com").unwrap();
+        assert_eq!(email.canonical(), email);
+
+        let email = Email::new("a+foo+bar@x.com").unwrap();
+        assert_eq!(email.canonical(), Email::new("a@x.com").unwrap());
+
+        let email = Email::new("+foo@x.com").unwrap();
+        assert_eq!(email.canonical(), email);
+    }
+
+    #[test]
+    fn email_length_limits_work() {
+        let local_part = "a".repeat(MAX_LOCAL_PART_LEN);
+        assert!(Email::new(&format!("{local_part}@example.com")).is_ok());
+        assert_eq!(
+            Email::new(&format!("{local_part}a@example.com")),
+            Err(Error::Validation(ValidationError::Email))
+        );
+
+        let domain = "b".repeat(MAX_LEN - MAX_LOCAL_PART_LEN - 5);
+        let email = format!("{local_part}@{domain}.com");
+        assert_eq!(email.len(), MAX_LEN);
+        assert!(Email::new(&email).is_ok());
+        assert_eq!(
+            Email::new(&format!("{local_part}@{domain}b.com")),
+            Err(Error::Validation(ValidationError::Email))
+        );
+    }
+
+    #[test]
+    fn email_blocklist_works() {
+        let blocklist =
+            HashSet::from(["mailinator.com".to_string(), "10minutemail.com".to_string()]);
+        assert!(Email::new_checked("john.doe@example.com", &blocklist).is_ok());
+        assert_eq!(
+            Email::new_checked("john.doe@Mailinator.com", &blocklist),
+            Err(Error::Validation(ValidationError::DisposableEmail))
+        );
+        assert_eq!(
+            Email::new_checked("not an email", &blocklist),
+            Err(Error::Validation(ValidationError::Email))
+        );
+    }
+
+    #[test]
+    fn email_custom_validator_works() {
+        // Accepts single-label domains, e.g. on an intranet, which the default rejects.
+        let intranet = |value: &str| {
+            value.split_once('@').is_some_and(|(local_part, domain)| {
+                !local_part.is_empty() && domain.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+        };
+        assert_eq!(
+            Email::new("john.doe@localhost"),
+            Err(Error::Validation(ValidationError::Email))
+        );
+        let email = Email::new_with_validator("john.doe@LocalHost", &intranet).unwrap();
+        assert_eq!(email.as_str(), "john.doe@localhost");
+
+        // Ensure that the custom validator replaces the default rules.
+        assert_eq!(
+            Email::new_with_validator("john.doe@example.com", &intranet),
+            Err(Error::Validation(ValidationError::Email))
+        );
+
+        // Accepts TLDs of any length, which the default limits to 63 characters.
+        let any_tld = |value: &str| {
+            value.rsplit_once('.').is_some_and(|(rest, tld)| {
+                rest.contains('@') && tld.chars().all(|c| c.is_ascii_alphabetic())
+            })
+        };
+        let long_tld = format!("john.doe@example.{}", "engineering".repeat(6));
+        assert_eq!(
+            Email::new(&long_tld),
+            Err(Error::Validation(ValidationError::Email))
+        );
+        assert!(Email::new_with_validator(&long_tld, &any_tld).is_ok());
+        assert!(Email::new_with_validator("john.doe@example.engineering", &any_tld).is_ok());
+
+        // Ensure that the length limits still apply.
+        let long = format!("{}@localhost", "a".repeat(MAX_LOCAL_PART_LEN + 1));
+        assert!(intranet(&long));
+        assert_eq!(
+            Email::new_with_validator(&long, &intranet),
+            Err(Error::Validation(ValidationError::Email))
+        );
+    }
+
+    #[cfg(feature = "idn")]
+    #[test]
+    fn email_idn_works() {
+        let email = Email::new("hans@müller.de").unwrap();
+        assert_eq!(email.domain(), "xn--mller-kva.de");
+        assert_eq!(email, Email::new("hans@MÜLLER.de").unwrap());
+
+        let email = Email::new("jürgen@example.com").unwrap();
+        assert_eq!(email.local_part(), "jürgen");
+    }
+
+    #[test]
+    fn email_validate_all_works() {
+        use crate::traits::validatable::combine;
+
+        struct Contact {
+            primary: Email,
+            secondary: Email,
+        }
+        impl ValidatableAll<Error> for Contact {
+            fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+                combine([self.primary.validate_all(), self.secondary.validate_all()])
+            }
+        }
+
+        let contact = Contact {
+            primary: Email("a".to_string()),
+            secondary: Email("a@".to_string()),
+        };
+        assert_eq!(
+            contact.validate_all(),
+            Err(vec![
+                Error::Validation(ValidationError::Email),
+                Error::Validation(ValidationError::Email),
+            ])
+        );
+
+        let contact = Contact {
+            primary: Email::new("john.doe@example.com").unwrap(),
+            secondary: Email("a@".to_string()),
+        };
+        assert_eq!(
+            contact.validate_all(),
+            Err(vec![Error::Validation(ValidationError::Email)])
+        );
+    }
+
+    #[test]
+    fn email_hash_works() {
+        let mut emails = HashSet::new();
+        emails.insert(Email::new("john.doe@example.com").unwrap());
+        assert!(emails.contains(&Email::new("john.doe@example.com").unwrap()));
+        assert!(!emails.contains(&Email::new("jane.doe@example.com").unwrap()));
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn email_schema_works() {
+        let schema = schemars::schema_for!(Email);
+        assert_eq!(schema.get("format").unwrap(), "email");
+        assert_eq!(schema.get("maxLength").unwrap(), MAX_LEN);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn email_serde_works() {
+        let email = Email::new("john.doe@example.com").unwrap();
+        let json = serde_json::to_string(&email).unwrap();
+        assert_eq!(json, r#""john.doe@example.com""#);
+        assert_eq!(serde_json::from_str::<Email>(&json).unwrap(), email);
+        assert!(serde_json::from_str::<Email>(r#""not an email""#).is_err());
+    }
+
+    #[test]
+    fn optional_email_validation_works() {
+        let email: Option<Email> = None;
+        assert!(email.validate().is_ok());
+        assert!(Some(Email::new("john.doe@example.com").unwrap())
+            .validate()
+            .is_ok());
+        assert_eq!(
+            Some(Email("john.doe".to_string())).validate(),
+            Err(Error::Validation(ValidationError::Email))
+        );
+    }
+
+    #[test]
+    fn email_list_validation_works() {
+        let emails: Vec<Email> = vec![];
+        assert!(emails.validate().is_ok());
+
+        let emails = vec![
+            Email::new("john.doe@example.com").unwrap(),
+            Email("jane.doe".to_string()),
+            Email::new("jane.doe@example.com").unwrap(),
+        ];
+        assert_eq!(
+            emails.validate(),
+            Err(Error::Validation(ValidationError::Email))
+        );
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn email_implements_postgres_types() {
+        fn assert_postgres<T>()
+        where
+            T: sqlx::Type<sqlx::Postgres>
+                + for<'q> sqlx::Encode<'q, sqlx::Postgres>
+                + for<'r> sqlx::Decode<'r, sqlx::Postgres>,
+        {
+        }
+        assert_postgres::<Email>();
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn email_sqlx_decode_validates() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let email: Email = sqlx::query_scalar("SELECT ?")
+            .bind(Email::new("john.doe@example.com").unwrap())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(email.as_str(), "john.doe@example.com");
+
+        let result = sqlx::query_scalar::<_, Email>("SELECT 'john.doe'")
+            .fetch_one(&pool)
+            .await;
+        assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+    }
 }
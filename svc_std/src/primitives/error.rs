@@ -1,12 +1,118 @@
 use fancy_regex::Error as RegexError;
 
-use crate::traits::password_hasher;
+use crate::traits::{password_hasher, validatable::FieldContext};
+
+/// Maximum number of characters of an input kept by `redact`.
+pub const REDACTED_PREFIX_LEN: usize = 3;
+
+/// Returns a redacted copy of the provided input that is safe to log.
+///
+/// Keeps up to `REDACTED_PREFIX_LEN` leading characters, but never more than half
+/// of the input, and masks the rest.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::error::redact;
+/// assert_eq!(redact("john.doe@example.com"), "joh***");
+/// assert_eq!(redact("jo"), "j***");
+/// ```
+pub fn redact(input: &str) -> String {
+    let len = REDACTED_PREFIX_LEN.min(input.chars().count() / 2);
+    format!("{}***", input.chars().take(len).collect::<String>())
+}
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ValidationError {
     Id,
     Email,
-    Password,
+    Password(PasswordValidationError),
+    BreachedPassword,
+    DisposableEmail,
+    TotpSecret,
+    DateTime,
+    Username,
+    PhoneNumber,
+    Url,
+    Empty,
+    UnresolvableDomain,
+    Role,
+    Missing,
+    CountryCode,
+    LanguageCode,
+    Currency,
+    PostalCode,
+
+    /// Indicates which field failed validation, e.g. `owner.email`.
+    Field {
+        path: String,
+        kind: Box<ValidationError>,
+    },
+
+    /// Carries a redacted copy of the offending input for diagnostics.
+    Input {
+        redacted: String,
+        kind: Box<ValidationError>,
+    },
+}
+
+impl ValidationError {
+    /// Returns the path of the field that failed validation, if known.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Self::Field { path, .. } => Some(path),
+            Self::Input { kind, .. } => kind.path(),
+            _ => None,
+        }
+    }
+
+    /// Returns the redacted offending input, if attached.
+    pub fn input(&self) -> Option<&str> {
+        match self {
+            Self::Input { redacted, .. } => Some(redacted),
+            Self::Field { kind, .. } => kind.input(),
+            _ => None,
+        }
+    }
+
+    /// Returns the validation error without field context or input.
+    pub fn kind(&self) -> &ValidationError {
+        match self {
+            Self::Field { kind, .. } | Self::Input { kind, .. } => kind.kind(),
+            _ => self,
+        }
+    }
+
+    /// Attaches a redacted copy of the provided offending input, see `redact`.
+    ///
+    /// Replaces a previously attached input.
+    pub fn with_input(self, input: &str) -> Self {
+        let redacted = redact(input);
+        match self {
+            Self::Field { path, kind } => Self::Field {
+                path,
+                kind: Box::new(kind.with_input(input)),
+            },
+            Self::Input { kind, .. } => Self::Input { redacted, kind },
+            kind => Self::Input {
+                redacted,
+                kind: Box::new(kind),
+            },
+        }
+    }
+}
+
+impl FieldContext for ValidationError {
+    fn at(self, field: &str) -> Self {
+        match self {
+            Self::Field { path, kind } => Self::Field {
+                path: format!("{field}.{path}"),
+                kind,
+            },
+            kind => Self::Field {
+                path: field.to_string(),
+                kind: Box::new(kind),
+            },
+        }
+    }
 }
 
 impl std::fmt::Display for ValidationError {
@@ -16,12 +122,56 @@ impl std::fmt::Display for ValidationError {
 }
 impl std::error::Error for ValidationError {}
 
+impl From<PasswordValidationError> for ValidationError {
+    fn from(value: PasswordValidationError) -> Self {
+        Self::Password(value)
+    }
+}
+
 impl From<uuid::Error> for ValidationError {
     fn from(_: uuid::Error) -> Self {
         Self::Id
     }
 }
 
+/// Indicates which password rule failed validation.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PasswordValidationError {
+    /// The password is empty, which is reported instead of any other rule.
+    Empty,
+
+    /// The password has fewer characters than required.
+    TooShort,
+
+    /// The password has more characters than allowed.
+    TooLong,
+
+    /// The password doesn't contain an uppercase letter.
+    MissingUppercase,
+
+    /// The password doesn't contain a lowercase letter.
+    MissingLowercase,
+
+    /// The password doesn't contain a digit.
+    MissingDigit,
+
+    /// The password doesn't contain a symbol.
+    MissingSymbol,
+
+    /// The password contains a forbidden word.
+    ForbiddenWord,
+
+    /// The password contains a run of more than two identical consecutive characters.
+    Repetitive,
+}
+
+impl std::fmt::Display for PasswordValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+impl std::error::Error for PasswordValidationError {}
+
 /// Primitives' error enum.
 ///
 /// Errors could be a validation or technical errors.
@@ -33,9 +183,47 @@ pub enum Error {
     /// Indicates that the validity of a password couldn't be confirmed.
     InvalidPassword,
 
+    /// Indicates that an account is temporarily locked after too many failed attempts.
+    AccountLocked,
+
+    /// Indicates that an email is already used by another user.
+    DuplicateEmail,
+
+    /// Indicates that the validity of an API key couldn't be confirmed.
+    InvalidApiKey,
+
+    /// Indicates that an API key has expired.
+    ExpiredApiKey,
+
+    /// Indicates that the validity of a reset token couldn't be confirmed.
+    InvalidResetToken,
+
+    /// Indicates that a reset token has expired.
+    ExpiredResetToken,
+
+    /// Indicates that the validity of a TOTP code couldn't be confirmed.
+    InvalidTotpCode,
+
+    /// Indicates that amounts of money in different currencies were combined.
+    CurrencyMismatch,
+
+    /// Indicates that an amount of money is too large to be represented.
+    AmountOverflow,
+
+    /// Technical error indicating that a stored password hash is corrupt or can't be parsed.
+    ///
+    /// Unlike `InvalidPassword` this points to a data integrity problem, not a failed sign in.
+    MalformedHash,
+
     /// Technical error indicating that a password hasher wasn't able to hash a password.
     PasswordHashingError(password_hasher::Error),
 
+    /// Technical error indicating that a breached password check couldn't be performed.
+    BreachCheckError(String),
+
+    /// Technical error indicating that a DNS lookup couldn't be performed.
+    DnsLookupError(String),
+
     /// Technical error indicating a problem with a regular expression.
     /// In most cases this error indicates that a regular expression couldn't be compiled.
     RegexError(String),
@@ -48,6 +236,33 @@ impl std::fmt::Display for Error {
 }
 impl std::error::Error for Error {}
 
+impl Error {
+    /// Attaches a redacted copy of the provided offending input to validation errors,
+    /// other errors are returned unchanged.
+    ///
+    /// ```rust
+    /// # use crate::svc_std::primitives::{Email, Error};
+    /// let error = Email::new("john.doe").map_err(|e| e.with_input("john.doe")).unwrap_err();
+    /// let Error::Validation(error) = error else { unreachable!() };
+    /// assert_eq!(error.input(), Some("joh***"));
+    /// ```
+    pub fn with_input(self, input: &str) -> Self {
+        match self {
+            Self::Validation(e) => Self::Validation(e.with_input(input)),
+            e => e,
+        }
+    }
+}
+
+impl FieldContext for Error {
+    fn at(self, field: &str) -> Self {
+        match self {
+            Self::Validation(e) => Self::Validation(e.at(field)),
+            e => e,
+        }
+    }
+}
+
 impl From<RegexError> for Error {
     fn from(value: RegexError) -> Self {
         Self::RegexError(value.to_string())
@@ -60,11 +275,60 @@ impl From<ValidationError> for Error {
     }
 }
 
+impl From<PasswordValidationError> for Error {
+    fn from(value: PasswordValidationError) -> Self {
+        Self::Validation(value.into())
+    }
+}
+
 impl From<password_hasher::Error> for Error {
     fn from(value: password_hasher::Error) -> Self {
         match value {
             password_hasher::Error::InvalidPassword => Self::InvalidPassword,
+            password_hasher::Error::MalformedHash => Self::MalformedHash,
             _ => Self::PasswordHashingError(value),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_truncates() {
+        assert_eq!(redact("john.doe@example.com"), "joh***");
+        assert_eq!(redact("john"), "jo***");
+        assert_eq!(redact("jo"), "j***");
+        assert_eq!(redact("j"), "***");
+        assert_eq!(redact(""), "***");
+
+        // Ensure that multi-byte characters aren't split.
+        assert_eq!(redact("müller@example.com"), "mül***");
+    }
+
+    #[test]
+    fn validation_error_input_works() {
+        let error = ValidationError::Email.with_input("john.doe");
+        assert_eq!(error.input(), Some("joh***"));
+        assert_eq!(error.kind(), &ValidationError::Email);
+        assert_eq!(error.path(), None);
+        assert!(!format!("{error:?}").contains("john.doe"));
+
+        // Ensure that the input and field context can be combined in any order.
+        for error in [
+            ValidationError::Email.with_input("john.doe").at("email"),
+            ValidationError::Email.at("email").with_input("john.doe"),
+        ] {
+            assert_eq!(error.input(), Some("joh***"));
+            assert_eq!(error.path(), Some("email"));
+            assert_eq!(error.kind(), &ValidationError::Email);
+        }
+
+        assert_eq!(ValidationError::Email.input(), None);
+        assert_eq!(
+            Error::InvalidPassword.with_input("john.doe"),
+            Error::InvalidPassword
+        );
+    }
+}
@@ -1,10 +1,31 @@
-use crate::traits::validatable::Validatable;
+use crate::traits::validatable::{Validatable, ValidatableAll};
+use std::marker::PhantomData;
+use subtle::ConstantTimeEq;
 use uuid::Uuid as CoreUuid;
 
 use super::{error::Error, ValidationError};
 
+/// Confirms whether the provided value is a valid uuid, without constructing a `Uuid`.
+///
+/// Accepts the same formats as `Uuid::try_from`.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::id::is_valid_uuid;
+/// assert!(is_valid_uuid("07a25b85-f1bb-4143-8e2e-5d8b4fb32f26"));
+/// assert!(!is_valid_uuid("234"));
+/// ```
+pub fn is_valid_uuid(value: &str) -> bool {
+    CoreUuid::try_parse(value).is_ok()
+}
+
 /// A validatable uuid field.
 ///
+/// Stores the native uuid, so validation happens once on construction and
+/// equality, ordering and hashing are based on the uuid bytes.
+///
+/// Parsing accepts the simple, hyphenated, braced and urn formats, while
+/// `Display` always renders the canonical hyphenated format.
+///
 /// ```rust
 /// # use crate::svc_std::{traits::Validatable, primitives::{Uuid, Error, ValidationError}};
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -17,15 +38,19 @@ use super::{error::Error, ValidationError};
 /// #    Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Uuid(String);
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Uuid(CoreUuid);
 
 impl Validatable<Error> for Uuid {
     fn validate(&self) -> crate::traits::validatable::Result<Error> {
-        match CoreUuid::parse_str(&self.0) {
-            Err(_) => Err(ValidationError::Id.into()),
-            Ok(_) => Ok(()),
-        }
+        // The native uuid is validated on construction.
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for Uuid {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
     }
 }
 
@@ -33,13 +58,112 @@ impl TryFrom<&str> for Uuid {
     type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let id = CoreUuid::parse_str(value)
-            .map_err(|_| ValidationError::Id)?
-            .to_string();
+        let id = CoreUuid::parse_str(value).map_err(ValidationError::from)?;
         Ok(Self(id))
     }
 }
 
+impl std::str::FromStr for Uuid {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Uuid::try_from(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Uuid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Uuid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Uuid::try_from(value.as_str()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Describes the uuid as a string in the `uuid` format, with the hyphenated pattern.
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Uuid {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Uuid".into()
+    }
+
+    fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "type": "string",
+            "format": "uuid",
+            "pattern": "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$",
+        })
+    }
+}
+
+/// Stores the uuid natively, e.g. `uuid` on Postgres.
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database> sqlx::Type<DB> for Uuid
+where
+    CoreUuid: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <CoreUuid as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <CoreUuid as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for Uuid
+where
+    CoreUuid: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for Uuid
+where
+    CoreUuid: sqlx::Decode<'r, DB>,
+{
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        Ok(Self(CoreUuid::decode(value)?))
+    }
+}
+
+/// Wraps a native uuid, which is always valid.
+impl From<CoreUuid> for Uuid {
+    fn from(value: CoreUuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Uuid> for CoreUuid {
+    fn from(value: Uuid) -> Self {
+        value.0
+    }
+}
+
+/// Converts into the canonical hyphenated format.
+///
+/// The uuid is stored natively, so there is no `as_str` or `AsRef<str>`.
+impl From<Uuid> for String {
+    fn from(value: Uuid) -> Self {
+        value.to_string()
+    }
+}
+
 impl std::fmt::Display for Uuid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.0)
@@ -48,7 +172,7 @@ impl std::fmt::Display for Uuid {
 
 impl Default for Uuid {
     fn default() -> Self {
-        Self(CoreUuid::new_v4().to_string())
+        Self(CoreUuid::new_v4())
     }
 }
 
@@ -57,6 +181,160 @@ impl Uuid {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Initializes a new uuid instance with a time-sortable v7 uuid.
+    ///
+    /// Uuids created in sequence by the same process sort in creation order,
+    /// even within the same millisecond, thanks to the counter in the random bits.
+    pub fn new_v7() -> Self {
+        Self(CoreUuid::now_v7())
+    }
+
+    /// Initializes a new uuid instance with the all-zero nil uuid.
+    ///
+    /// Useful as a sentinel for entities that have no id yet.
+    pub fn nil() -> Self {
+        Self(CoreUuid::nil())
+    }
+
+    /// Confirms whether the uuid is the nil uuid.
+    pub fn is_nil(&self) -> bool {
+        self.0.is_nil()
+    }
+
+    /// Confirms whether the uuids are equal in constant time.
+    pub fn ct_eq(&self, other: &Uuid) -> bool {
+        self.0.as_bytes().ct_eq(other.0.as_bytes()).into()
+    }
+
+    /// Returns the version number of the uuid.
+    pub fn version(&self) -> usize {
+        self.0.get_version_num()
+    }
+}
+
+/// A uuid based id, typed by the entity it identifies.
+///
+/// Prevents mixing up ids of different entities at compile time.
+///
+/// ```rust
+/// # use crate::svc_std::{traits::Validatable, primitives::Id};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     struct User;
+///     let id = Id::<User>::new();
+///     assert!(id.validate().is_ok());
+///
+///     let id: Id<User> = "07a25b85-f1bb-4143-8e2e-5d8b4fb32f26".try_into()?;
+///     assert_eq!(id.to_string(), "07a25b85-f1bb-4143-8e2e-5d8b4fb32f26");
+/// #    Ok(())
+/// # }
+/// ```
+///
+/// Ids of different entities can't be mixed up:
+///
+/// ```rust,compile_fail
+/// # use crate::svc_std::primitives::Id;
+/// struct User;
+/// struct Order;
+/// let user_id: Id<User> = Id::<Order>::new();
+/// ```
+pub struct Id<T>(Uuid, PhantomData<fn() -> T>);
+
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> std::fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Id").field(&self.0).finish()
+    }
+}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> std::hash::Hash for Id<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<T> Validatable<Error> for Id<T> {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        self.0.validate()
+    }
+}
+
+impl<T> TryFrom<&str> for Id<T> {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self(Uuid::try_from(value)?, PhantomData))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Id<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Id<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(Uuid::deserialize(deserializer)?, PhantomData))
+    }
+}
+
+impl<T> std::fmt::Display for Id<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<T> Default for Id<T> {
+    fn default() -> Self {
+        Self(Uuid::default(), PhantomData)
+    }
+}
+
+impl<T> Id<T> {
+    /// Initializes a new id instance with a random v4 uuid.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Initializes a new id instance with the all-zero nil uuid.
+    pub fn nil() -> Self {
+        Self(Uuid::nil(), PhantomData)
+    }
+
+    /// Confirms whether the id is the nil uuid.
+    pub fn is_nil(&self) -> bool {
+        self.0.is_nil()
+    }
 }
 
 #[cfg(test)]
@@ -66,10 +344,222 @@ mod tests {
     #[test]
     fn email_validation_works() {
         assert!(Uuid::new().validate().is_ok());
+        assert!(Uuid::new().validate_all().is_ok());
         assert!(Uuid::try_from("ebf8a4f3-b481-474c-ae29-c71e975e1055").is_ok());
         assert_eq!(
             Uuid::try_from("123"),
             Err(Error::Validation(ValidationError::Id))
         );
     }
+
+    #[test]
+    fn is_valid_uuid_works() {
+        for value in [
+            "ebf8a4f3-b481-474c-ae29-c71e975e1055",
+            "ebf8a4f3b481474cae29c71e975e1055",
+            "{ebf8a4f3-b481-474c-ae29-c71e975e1055}",
+            "urn:uuid:ebf8a4f3-b481-474c-ae29-c71e975e1055",
+            "123",
+            "",
+            "ebf8a4f3-b481-474c-ae29-c71e975e105g",
+        ] {
+            assert_eq!(
+                is_valid_uuid(value),
+                Uuid::try_from(value).is_ok(),
+                "{value}"
+            );
+        }
+        assert!(is_valid_uuid("ebf8a4f3-b481-474c-ae29-c71e975e1055"));
+        assert!(!is_valid_uuid("123"));
+    }
+
+    #[test]
+    fn uuid_string_conversion_works() {
+        let uuid = Uuid::try_from("EBF8A4F3B481474CAE29C71E975E1055").unwrap();
+        assert_eq!(String::from(uuid), "ebf8a4f3-b481-474c-ae29-c71e975e1055");
+        let value: String = uuid.into();
+        assert_eq!(Uuid::try_from(value.as_str()), Ok(uuid));
+    }
+
+    #[test]
+    fn uuid_parse_works() {
+        let uuid: Uuid = "ebf8a4f3-b481-474c-ae29-c71e975e1055".parse().unwrap();
+        assert_eq!(uuid.to_string(), "ebf8a4f3-b481-474c-ae29-c71e975e1055");
+        assert_eq!(uuid.to_string().parse(), Ok(uuid));
+        assert_eq!(
+            "234".parse::<Uuid>(),
+            Err(Error::Validation(ValidationError::Id))
+        );
+    }
+
+    #[test]
+    fn uuid_ct_eq_works() {
+        let uuid = Uuid::new();
+        let copy = Uuid::try_from(uuid.to_string().as_str()).unwrap();
+        assert_eq!(uuid.ct_eq(&copy), uuid == copy);
+        assert!(uuid.ct_eq(&copy));
+        assert!(!uuid.ct_eq(&Uuid::new()));
+    }
+
+    #[test]
+    fn typed_id_works() {
+        struct Order;
+
+        let id = Id::<Order>::try_from("07a25b85-f1bb-4143-8e2e-5d8b4fb32f26").unwrap();
+        assert!(id.validate().is_ok());
+        assert_eq!(id, id.clone());
+        assert_ne!(id, Id::<Order>::new());
+        assert_eq!(id.to_string(), "07a25b85-f1bb-4143-8e2e-5d8b4fb32f26");
+        assert_eq!(
+            Id::<Order>::try_from("123"),
+            Err(Error::Validation(ValidationError::Id))
+        );
+    }
+
+    #[test]
+    fn uuid_formats_normalize() {
+        let expected = "07a25b85-f1bb-4143-8e2e-5d8b4fb32f26";
+        for input in [
+            "07a25b85-f1bb-4143-8e2e-5d8b4fb32f26",
+            "07a25b85f1bb41438e2e5d8b4fb32f26",
+            "{07a25b85-f1bb-4143-8e2e-5d8b4fb32f26}",
+            "urn:uuid:07a25b85-f1bb-4143-8e2e-5d8b4fb32f26",
+        ] {
+            let uuid = Uuid::try_from(input).unwrap();
+            assert_eq!(uuid, Uuid::try_from(expected).unwrap());
+            assert_eq!(uuid.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn uuid_hash_works() {
+        let id = Uuid::new();
+        let mut ids = std::collections::HashSet::new();
+        ids.insert(id);
+        assert!(ids.contains(&Uuid::try_from(id.to_string().as_str()).unwrap()));
+        assert!(!ids.contains(&Uuid::new()));
+    }
+
+    #[test]
+    fn uuid_nil_works() {
+        assert!(Uuid::nil().is_nil());
+        assert_eq!(
+            Uuid::nil().to_string(),
+            "00000000-0000-0000-0000-000000000000"
+        );
+        assert!(!Uuid::new().is_nil());
+        assert!(!Uuid::new_v7().is_nil());
+        assert!(Id::<()>::nil().is_nil());
+        assert!(!Id::<()>::new().is_nil());
+    }
+
+    #[test]
+    fn uuid_v7_works() {
+        assert_eq!(Uuid::new().version(), 4);
+        assert_eq!(Uuid::new_v7().version(), 7);
+
+        let ids: Vec<Uuid> = (0..100).map(|_| Uuid::new_v7()).collect();
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(ids, sorted);
+    }
+
+    #[test]
+    fn uuid_equality_and_ordering_work() {
+        let a = Uuid::try_from("07a25b85-f1bb-4143-8e2e-5d8b4fb32f26").unwrap();
+        let b = Uuid::try_from("07A25B85-F1BB-4143-8E2E-5D8B4FB32F26").unwrap();
+        let c = Uuid::try_from("ebf8a4f3-b481-474c-ae29-c71e975e1055").unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a < c);
+        assert_eq!(b.to_string(), "07a25b85-f1bb-4143-8e2e-5d8b4fb32f26");
+
+        let mut ids = vec![c, a];
+        ids.sort();
+        assert_eq!(ids, vec![a, c]);
+    }
+
+    #[test]
+    fn uuid_ordering_matches_native() {
+        let mut ids: Vec<Uuid> = (0..32)
+            .map(|i| match i % 2 {
+                0 => Uuid::new(),
+                _ => Uuid::new_v7(),
+            })
+            .chain([Uuid::nil()])
+            .collect();
+        let mut native: Vec<CoreUuid> = ids.iter().map(|id| id.0).collect();
+
+        ids.sort();
+        native.sort();
+        assert_eq!(ids.iter().map(|id| id.0).collect::<Vec<_>>(), native);
+        assert_eq!(ids[0], Uuid::nil());
+
+        // Ensure that the order follows the bytes rather than the input string.
+        let a = "B0000000-0000-4000-8000-000000000000";
+        let b = "a0000000-0000-4000-8000-000000000000";
+        assert!(a < b);
+        assert!(Uuid::try_from(a).unwrap() > Uuid::try_from(b).unwrap());
+    }
+
+    #[test]
+    fn uuid_native_conversion_works() {
+        let native = CoreUuid::new_v4();
+        let uuid = Uuid::from(native);
+        assert_eq!(uuid.to_string(), native.to_string());
+        assert_eq!(CoreUuid::from(uuid), native);
+
+        let uuid = Uuid::new();
+        let native: CoreUuid = uuid.into();
+        assert_eq!(Uuid::from(native), uuid);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn uuid_schema_works() {
+        let schema = schemars::schema_for!(Uuid);
+        assert_eq!(schema.get("format").unwrap(), "uuid");
+
+        // Ensure that the pattern matches serialized uuids.
+        let pattern = schema.get("pattern").unwrap().as_str().unwrap();
+        let regex = fancy_regex::Regex::new(pattern).unwrap();
+        assert!(regex.is_match(&Uuid::new().to_string()).unwrap());
+        assert!(!regex.is_match("07a25b85f1bb41438e2e5d8b4fb32f26").unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn uuid_serde_works() {
+        let uuid = Uuid::new();
+        let json = serde_json::to_string(&uuid).unwrap();
+        assert_eq!(json, format!(r#""{uuid}""#));
+        assert_eq!(serde_json::from_str::<Uuid>(&json).unwrap(), uuid);
+        assert!(serde_json::from_str::<Uuid>(r#""123""#).is_err());
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn uuid_implements_postgres_types() {
+        fn assert_postgres<T>()
+        where
+            T: sqlx::Type<sqlx::Postgres>
+                + for<'q> sqlx::Encode<'q, sqlx::Postgres>
+                + for<'r> sqlx::Decode<'r, sqlx::Postgres>,
+        {
+        }
+        assert_postgres::<Uuid>();
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[tokio::test]
+    async fn uuid_sqlx_round_trip_works() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let uuid = Uuid::new();
+        let decoded: Uuid = sqlx::query_scalar("SELECT ?")
+            .bind(uuid)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(decoded, uuid);
+    }
 }
@@ -0,0 +1,134 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+
+/// ISO 639-1 codes, sorted for binary search.
+const CODES: &[&str] = &[
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bi",
+    "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da", "de",
+    "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr", "fy",
+    "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz", "ia",
+    "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj", "kk",
+    "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln", "lo",
+    "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb", "nd",
+    "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi", "pl",
+    "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk", "sl",
+    "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti", "tk",
+    "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "wa",
+    "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// A validatable ISO 639-1 language code.
+///
+/// Codes are normalized to lowercase and must be part of the standard.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{Error, LanguageCode, ValidationError};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let language = LanguageCode::new("EN")?;
+///     assert_eq!(language.as_str(), "en");
+///     assert_eq!(LanguageCode::new("xx"), Err(Error::Validation(ValidationError::LanguageCode)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LanguageCode(String);
+
+impl Validatable<Error> for LanguageCode {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        if CODES.binary_search(&self.0.as_str()).is_err() {
+            return Err(ValidationError::LanguageCode.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for LanguageCode {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for LanguageCode {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        LanguageCode::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for LanguageCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LanguageCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        LanguageCode::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl LanguageCode {
+    /// Initializes a new language code instance, normalized to lowercase.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(value.to_ascii_lowercase());
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the language code as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_codes_are_sorted() {
+        assert_eq!(CODES.len(), 183);
+        assert!(CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn language_code_validation_works() {
+        let en = LanguageCode::new("EN").unwrap();
+        assert_eq!(en, LanguageCode::new("en").unwrap());
+        assert_eq!(en.to_string(), "en");
+        assert_eq!(LanguageCode::try_from("nl").unwrap().as_str(), "nl");
+
+        for value in ["", "e", "eng", "xx", "qq", "e1", "en-US"] {
+            assert_eq!(
+                LanguageCode::new(value),
+                Err(Error::Validation(ValidationError::LanguageCode)),
+                "{value}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn language_code_serde_works() {
+        let language = LanguageCode::new("de").unwrap();
+        let json = serde_json::to_string(&language).unwrap();
+        assert_eq!(json, r#""de""#);
+        assert_eq!(
+            serde_json::from_str::<LanguageCode>(r#""DE""#).unwrap(),
+            language
+        );
+        assert!(serde_json::from_str::<LanguageCode>(r#""xx""#).is_err());
+    }
+}
@@ -1,15 +1,43 @@
 //! Module providing validatable primitive types.
 
+pub mod country;
 pub mod datetime;
 pub mod email;
 pub mod error;
 pub mod id;
+pub mod language;
+pub mod money;
+pub mod non_empty;
 pub mod password;
+pub mod phone;
+pub mod postal;
+pub mod reset_token;
+pub mod role;
+pub mod secret;
+pub mod token;
+#[cfg(feature = "totp")]
+pub mod totp;
+pub mod url;
 pub mod user;
+pub mod username;
 
+pub use self::url::Url;
+pub use country::CountryCode;
 pub use datetime::DateTime;
 pub use email::Email;
-pub use error::{Error, ValidationError};
-pub use id::Uuid;
-pub use password::Password;
-pub use user::User;
+pub use error::{Error, PasswordValidationError, ValidationError};
+pub use id::{Id, Uuid};
+pub use language::LanguageCode;
+pub use money::{Currency, Money};
+pub use non_empty::NonEmptyString;
+pub use password::{Password, PasswordPolicy, Strength};
+pub use phone::PhoneNumber;
+pub use postal::PostalCode;
+pub use reset_token::ResetToken;
+pub use role::{Role, RolePermissions, Roles};
+pub use secret::SecretString;
+pub use token::ApiKey;
+#[cfg(feature = "totp")]
+pub use totp::Totp;
+pub use user::{User, UserEvent};
+pub use username::Username;
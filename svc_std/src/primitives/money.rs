@@ -0,0 +1,449 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{FieldContext, Validatable, ValidatableAll};
+
+/// Active ISO 4217 currency codes and their number of minor units,
+/// sorted for binary search. Fund and precious metal codes are excluded.
+const CURRENCIES: &[(&str, u32)] = &[
+    ("AED", 2),
+    ("AFN", 2),
+    ("ALL", 2),
+    ("AMD", 2),
+    ("ANG", 2),
+    ("AOA", 2),
+    ("ARS", 2),
+    ("AUD", 2),
+    ("AWG", 2),
+    ("AZN", 2),
+    ("BAM", 2),
+    ("BBD", 2),
+    ("BDT", 2),
+    ("BGN", 2),
+    ("BHD", 3),
+    ("BIF", 0),
+    ("BMD", 2),
+    ("BND", 2),
+    ("BOB", 2),
+    ("BRL", 2),
+    ("BSD", 2),
+    ("BTN", 2),
+    ("BWP", 2),
+    ("BYN", 2),
+    ("BZD", 2),
+    ("CAD", 2),
+    ("CDF", 2),
+    ("CHF", 2),
+    ("CLP", 0),
+    ("CNY", 2),
+    ("COP", 2),
+    ("CRC", 2),
+    ("CUP", 2),
+    ("CVE", 2),
+    ("CZK", 2),
+    ("DJF", 0),
+    ("DKK", 2),
+    ("DOP", 2),
+    ("DZD", 2),
+    ("EGP", 2),
+    ("ERN", 2),
+    ("ETB", 2),
+    ("EUR", 2),
+    ("FJD", 2),
+    ("FKP", 2),
+    ("GBP", 2),
+    ("GEL", 2),
+    ("GHS", 2),
+    ("GIP", 2),
+    ("GMD", 2),
+    ("GNF", 0),
+    ("GTQ", 2),
+    ("GYD", 2),
+    ("HKD", 2),
+    ("HNL", 2),
+    ("HTG", 2),
+    ("HUF", 2),
+    ("IDR", 2),
+    ("ILS", 2),
+    ("INR", 2),
+    ("IQD", 3),
+    ("IRR", 2),
+    ("ISK", 0),
+    ("JMD", 2),
+    ("JOD", 3),
+    ("JPY", 0),
+    ("KES", 2),
+    ("KGS", 2),
+    ("KHR", 2),
+    ("KMF", 0),
+    ("KPW", 2),
+    ("KRW", 0),
+    ("KWD", 3),
+    ("KYD", 2),
+    ("KZT", 2),
+    ("LAK", 2),
+    ("LBP", 2),
+    ("LKR", 2),
+    ("LRD", 2),
+    ("LSL", 2),
+    ("LYD", 3),
+    ("MAD", 2),
+    ("MDL", 2),
+    ("MGA", 2),
+    ("MKD", 2),
+    ("MMK", 2),
+    ("MNT", 2),
+    ("MOP", 2),
+    ("MRU", 2),
+    ("MUR", 2),
+    ("MVR", 2),
+    ("MWK", 2),
+    ("MXN", 2),
+    ("MYR", 2),
+    ("MZN", 2),
+    ("NAD", 2),
+    ("NGN", 2),
+    ("NIO", 2),
+    ("NOK", 2),
+    ("NPR", 2),
+    ("NZD", 2),
+    ("OMR", 3),
+    ("PAB", 2),
+    ("PEN", 2),
+    ("PGK", 2),
+    ("PHP", 2),
+    ("PKR", 2),
+    ("PLN", 2),
+    ("PYG", 0),
+    ("QAR", 2),
+    ("RON", 2),
+    ("RSD", 2),
+    ("RUB", 2),
+    ("RWF", 0),
+    ("SAR", 2),
+    ("SBD", 2),
+    ("SCR", 2),
+    ("SDG", 2),
+    ("SEK", 2),
+    ("SGD", 2),
+    ("SHP", 2),
+    ("SLE", 2),
+    ("SOS", 2),
+    ("SRD", 2),
+    ("SSP", 2),
+    ("STN", 2),
+    ("SVC", 2),
+    ("SYP", 2),
+    ("SZL", 2),
+    ("THB", 2),
+    ("TJS", 2),
+    ("TMT", 2),
+    ("TND", 3),
+    ("TOP", 2),
+    ("TRY", 2),
+    ("TTD", 2),
+    ("TWD", 2),
+    ("TZS", 2),
+    ("UAH", 2),
+    ("UGX", 0),
+    ("USD", 2),
+    ("UYU", 2),
+    ("UZS", 2),
+    ("VES", 2),
+    ("VND", 0),
+    ("VUV", 0),
+    ("WST", 2),
+    ("XAF", 0),
+    ("XCD", 2),
+    ("XCG", 2),
+    ("XOF", 0),
+    ("XPF", 0),
+    ("YER", 2),
+    ("ZAR", 2),
+    ("ZMW", 2),
+    ("ZWG", 2),
+];
+
+/// A validatable ISO 4217 currency code.
+///
+/// Codes are normalized to uppercase and must be active.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{Currency, Error, ValidationError};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let currency = Currency::new("eur")?;
+///     assert_eq!(currency.as_str(), "EUR");
+///     assert_eq!(currency.minor_units(), 2);
+///     assert_eq!(Currency::new("XXX"), Err(Error::Validation(ValidationError::Currency)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Currency(String);
+
+impl Validatable<Error> for Currency {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        self.lookup()?;
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for Currency {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for Currency {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Currency::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Currency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Currency::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Currency {
+    /// Initializes a new currency instance, normalized to uppercase.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(value.to_ascii_uppercase());
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the currency code as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the number of minor units, e.g. 2 for cents.
+    pub fn minor_units(&self) -> u32 {
+        // The currency is validated on construction, so the lookup always succeeds.
+        self.lookup().map(|(_, units)| units).unwrap_or(0)
+    }
+
+    /// Returns the symbol of widely used currencies, others are displayed by their code.
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self.0.as_str() {
+            "EUR" => Some("€"),
+            "GBP" => Some("£"),
+            "INR" => Some("₹"),
+            "JPY" => Some("¥"),
+            "KRW" => Some("₩"),
+            "USD" => Some("$"),
+            _ => None,
+        }
+    }
+
+    fn lookup(&self) -> Result<(&'static str, u32), Error> {
+        CURRENCIES
+            .binary_search_by(|(code, _)| (*code).cmp(self.0.as_str()))
+            .map(|i| CURRENCIES[i])
+            .map_err(|_| ValidationError::Currency.into())
+    }
+}
+
+/// An amount of money in the minor units of its currency, e.g. cents.
+///
+/// Amounts are integers to avoid floating point rounding errors, and arithmetic
+/// is checked, rejecting overflows and amounts in different currencies.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{Currency, Error, Money};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let price = Money::new(1999, Currency::new("USD")?);
+///     let shipping = Money::new(499, Currency::new("USD")?);
+///     assert_eq!(price.checked_add(&shipping)?.to_string(), "$24.98");
+///
+///     let fee = Money::new(100, Currency::new("EUR")?);
+///     assert_eq!(price.checked_add(&fee), Err(Error::CurrencyMismatch));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Money {
+    amount: i64,
+    currency: Currency,
+}
+
+impl Validatable<Error> for Money {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        self.currency.validate().map_err(|e| e.at("currency"))
+    }
+}
+
+impl ValidatableAll<Error> for Money {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+/// Formats the amount with its currency symbol, or its code if there is none,
+/// e.g. `-$12.34`, `¥1234` or `12.345 KWD`.
+impl std::fmt::Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let units = self.currency.minor_units();
+        let divisor = 10u64.pow(units);
+        let major = self.amount.unsigned_abs() / divisor;
+        let minor = self.amount.unsigned_abs() % divisor;
+        let amount = match units {
+            0 => major.to_string(),
+            _ => format!("{major}.{minor:0width$}", width = units as usize),
+        };
+
+        match self.currency.symbol() {
+            Some(symbol) => write!(f, "{sign}{symbol}{amount}"),
+            None => write!(f, "{sign}{amount} {}", self.currency),
+        }
+    }
+}
+
+impl Money {
+    /// Initializes a new money instance with the provided amount in minor units.
+    pub fn new(amount: i64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Returns the amount in minor units.
+    pub fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// Returns the currency.
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Adds the provided amount.
+    ///
+    /// Returns `Error::CurrencyMismatch` if the currencies differ,
+    /// or `Error::AmountOverflow` if the result doesn't fit.
+    pub fn checked_add(&self, other: &Money) -> Result<Money, Error> {
+        self.combine(other, i64::checked_add)
+    }
+
+    /// Subtracts the provided amount.
+    ///
+    /// Returns `Error::CurrencyMismatch` if the currencies differ,
+    /// or `Error::AmountOverflow` if the result doesn't fit.
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, Error> {
+        self.combine(other, i64::checked_sub)
+    }
+
+    fn combine(
+        &self,
+        other: &Money,
+        f: impl FnOnce(i64, i64) -> Option<i64>,
+    ) -> Result<Money, Error> {
+        if self.currency != other.currency {
+            return Err(Error::CurrencyMismatch);
+        }
+        let amount = f(self.amount, other.amount).ok_or(Error::AmountOverflow)?;
+        Ok(Money::new(amount, self.currency.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn money(amount: i64, currency: &str) -> Money {
+        Money::new(amount, Currency::new(currency).unwrap())
+    }
+
+    #[test]
+    fn currencies_are_sorted() {
+        assert!(CURRENCIES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn currency_validation_works() {
+        let usd = Currency::new("usd").unwrap();
+        assert_eq!(usd, Currency::try_from("USD").unwrap());
+        assert_eq!(usd.minor_units(), 2);
+        assert_eq!(Currency::new("JPY").unwrap().minor_units(), 0);
+        assert_eq!(Currency::new("KWD").unwrap().minor_units(), 3);
+
+        for value in ["", "US", "USDD", "XXX", "XAU"] {
+            assert_eq!(
+                Currency::new(value),
+                Err(Error::Validation(ValidationError::Currency)),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn money_arithmetic_works() {
+        assert_eq!(
+            money(1999, "USD").checked_add(&money(1, "USD")),
+            Ok(money(2000, "USD"))
+        );
+        assert_eq!(
+            money(1999, "USD").checked_sub(&money(2000, "USD")),
+            Ok(money(-1, "USD"))
+        );
+        assert_eq!(
+            money(i64::MAX, "USD").checked_add(&money(1, "USD")),
+            Err(Error::AmountOverflow)
+        );
+    }
+
+    #[test]
+    fn money_rejects_currency_mismatch() {
+        assert_eq!(
+            money(100, "USD").checked_add(&money(100, "EUR")),
+            Err(Error::CurrencyMismatch)
+        );
+        assert_eq!(
+            money(100, "USD").checked_sub(&money(100, "EUR")),
+            Err(Error::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn money_formatting_works() {
+        assert_eq!(money(1234, "USD").to_string(), "$12.34");
+        assert_eq!(money(-1234, "EUR").to_string(), "-€12.34");
+        assert_eq!(money(5, "GBP").to_string(), "£0.05");
+        assert_eq!(money(1234, "JPY").to_string(), "¥1234");
+        assert_eq!(money(12345, "KWD").to_string(), "12.345 KWD");
+        assert_eq!(money(100, "CHF").to_string(), "1.00 CHF");
+        assert_eq!(
+            money(i64::MIN, "CHF").to_string(),
+            "-92233720368547758.08 CHF"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn money_serde_works() {
+        let money = money(1234, "EUR");
+        let json = serde_json::to_string(&money).unwrap();
+        assert_eq!(json, r#"{"amount":1234,"currency":"EUR"}"#);
+        assert_eq!(serde_json::from_str::<Money>(&json).unwrap(), money);
+        assert!(serde_json::from_str::<Money>(r#"{"amount":1,"currency":"XXX"}"#).is_err());
+    }
+}
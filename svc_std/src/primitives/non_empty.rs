@@ -0,0 +1,115 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+
+/// A validatable string field that must not be blank.
+///
+/// Leading and trailing whitespace is trimmed on storage and
+/// empty or whitespace-only input is rejected.
+///
+/// ```rust
+/// # use crate::svc_std::{traits::Validatable, primitives::{Error, NonEmptyString, ValidationError}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let name = NonEmptyString::new("  John Doe ")?;
+///     assert!(name.validate().is_ok());
+///     assert_eq!(name.as_ref(), "John Doe");
+///     assert_eq!(NonEmptyString::new(" "), Err(Error::Validation(ValidationError::Empty)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NonEmptyString(String);
+
+impl Validatable<Error> for NonEmptyString {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        if self.0.trim().is_empty() {
+            return Err(ValidationError::Empty.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for NonEmptyString {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for NonEmptyString {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        NonEmptyString::new(value)
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NonEmptyString {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NonEmptyString {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        NonEmptyString::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for NonEmptyString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl NonEmptyString {
+    /// Initializes a new instance with the trimmed value.
+    ///
+    /// Returns a validation error if the trimmed value is empty.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(value.trim().to_string());
+        v.validate()?;
+        Ok(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_empty_string_validation_works() {
+        for value in ["", " ", "\t\n", "\u{3000}"] {
+            assert_eq!(
+                NonEmptyString::new(value),
+                Err(Error::Validation(ValidationError::Empty)),
+                "{value:?}"
+            );
+        }
+
+        let value = NonEmptyString::new(" \tJohn Doe\n").unwrap();
+        assert_eq!(value.as_ref(), "John Doe");
+        assert_eq!(value.to_string(), "John Doe");
+        assert_eq!(value, "John Doe".try_into().unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn non_empty_string_serde_works() {
+        let value = NonEmptyString::new("John Doe").unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, r#""John Doe""#);
+        assert_eq!(
+            serde_json::from_str::<NonEmptyString>(&json).unwrap(),
+            value
+        );
+        assert!(serde_json::from_str::<NonEmptyString>(r#"" ""#).is_err());
+    }
+}
@@ -1,71 +1,498 @@
-use crate::traits::password_hasher::PasswordHasher;
-use fancy_regex::Regex;
+use crate::traits::password_hasher::{ConfiguredPasswordHasher, PasswordHasher};
 use std::marker::PhantomData;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+use zeroize::Zeroizing;
 
-use super::{error::Error, ValidationError};
+#[cfg(feature = "hibp")]
+use super::ValidationError;
+use super::{error::Error, PasswordValidationError};
+
+/// Symbols accepted by the `require_symbol` password rule.
+pub const PASSWORD_SYMBOLS: &str = "#$%/()=¿?*+-";
+
+/// Default maximum number of characters, long enough for passphrases and
+/// generated secrets.
+pub const DEFAULT_MAX_LEN: usize = 128;
+
+/// Maximum number of characters regardless of the policy, which bounds the work
+/// of validating and hashing untrusted input.
+pub const MAX_LEN_LIMIT: usize = 1024;
+
+/// Maximum number of identical consecutive characters, e.g. `aa` is allowed but `aaa` isn't.
+pub const MAX_REPEATED_CHARS: usize = 2;
+
+/// Confirms whether the provided value contains a run of more than
+/// `MAX_REPEATED_CHARS` identical consecutive characters, compared case-sensitively.
+fn has_repeated_run(value: &str) -> bool {
+    let mut chars = value.chars();
+    let Some(mut previous) = chars.next() else {
+        return false;
+    };
+    let mut run = 1;
+    for c in chars {
+        run = if c == previous { run + 1 } else { 1 };
+        if run > MAX_REPEATED_CHARS {
+            return true;
+        }
+        previous = c;
+    }
+    false
+}
+
+/// Policy for password validation.
+///
+/// The default policy requires a length between 8 and 128 characters and a
+/// combination of lowercase, uppercase, digits and symbols.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{Password, PasswordPolicy, PasswordValidationError, Error, ValidationError}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let policy = PasswordPolicy {
+///         forbidden_words: vec!["sonemas".to_string()],
+///         ..Default::default()
+///     };
+///     assert_eq!(
+///         Password::<Argon2PasswordHasher>::new_with_policy("SoNeMaS123*", &policy),
+///         Err(Error::Validation(ValidationError::Password(PasswordValidationError::ForbiddenWord)))
+///     );
+///
+///     let policy = PasswordPolicy {
+///         require_symbol: false,
+///         ..Default::default()
+///     };
+///     assert!(Password::<Argon2PasswordHasher>::new_with_policy("mmholAhsbC123", &policy).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PasswordPolicy {
+    /// Minimum number of characters.
+    pub min_len: usize,
+
+    /// Maximum number of characters, capped at `MAX_LEN_LIMIT`.
+    pub max_len: usize,
+
+    /// Whether at least one uppercase letter is required.
+    pub require_upper: bool,
+
+    /// Whether at least one lowercase letter is required.
+    pub require_lower: bool,
+
+    /// Whether at least one digit is required.
+    pub require_digit: bool,
+
+    /// Whether at least one of the `PASSWORD_SYMBOLS` is required.
+    pub require_symbol: bool,
+
+    /// Words that may not be contained in a password, matched case-insensitively.
+    pub forbidden_words: Vec<String>,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self {
+            min_len: 8,
+            max_len: DEFAULT_MAX_LEN,
+            require_upper: true,
+            require_lower: true,
+            require_digit: true,
+            require_symbol: true,
+            forbidden_words: Vec::new(),
+        }
+    }
+}
 
 /// A password field with built-in validation and hashing.
 ///
 /// Validation is done when initializing a new instance with new. Only stores the password hash.
-/// A password is considered valid when it has:
-/// - a length between 8 and 20 characters
+/// A password is considered valid when it satisfies the default `PasswordPolicy`:
+/// - a length between 8 and 128 characters
 /// - a combination of lowercase, uppercase, digits and symbols
+///
+/// The plaintext is normalized to Unicode normalization form C (NFC) before
+/// validation, hashing and confirmation, so visually identical passwords entered
+/// in different forms, e.g. a decomposed `é`, match.
+///
+/// Plaintext that is already in NFC is validated and hashed in place, without
+/// intermediate copies, other plaintext is normalized into a copy that is wiped
+/// on drop. Owned plaintext converted with `TryFrom<String>` or
+/// `TryFrom<Zeroizing<String>>` is wiped once the password has been hashed.
+/// The `new` functions only borrow their input, wiping it is up to the caller,
+/// e.g. by keeping it in a `SecretString`. The Argon2 working memory is zeroized
+/// after hashing.
 /// ```rust
-/// # use crate::svc_std::{traits::Validatable, password_hasher::argon2::Argon2PasswordHasher, primitives::{Password, Error, ValidationError}};
+/// # use crate::svc_std::{traits::Validatable, password_hasher::argon2::Argon2PasswordHasher, primitives::{Password, PasswordValidationError, Error, ValidationError}};
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let password: Password<Argon2PasswordHasher> = Password::new("mmholAhsbC123*")?;
 ///     assert!(password.confirm("mmholAhsbC123*").is_ok());
 ///     assert_eq!(password.confirm("blabla"), Err(Error::InvalidPassword));
 ///
 ///     let password: Password<Argon2PasswordHasher> = "mmholAhsbC123*".try_into()?;
-///     assert_eq!(Password::<Argon2PasswordHasher>::try_from("aaa"), Err(Error::Validation(ValidationError::Password)));
+///     assert_eq!(Password::<Argon2PasswordHasher>::try_from("aaa"), Err(Error::Validation(ValidationError::Password(PasswordValidationError::TooShort))));
 /// #    Ok(())
 /// # }
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Password<T: PasswordHasher>(String, std::marker::PhantomData<T>);
 
-impl<T: PasswordHasher> TryFrom<&'static str> for Password<T> {
+impl<T: PasswordHasher> TryFrom<&str> for Password<T> {
     type Error = Error;
 
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         Self::new(value)
     }
 }
 
-impl<T: PasswordHasher> ToString for Password<T> {
-    fn to_string(&self) -> String {
-        self.0.clone()
+impl<T: PasswordHasher> TryFrom<String> for Password<T> {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(Zeroizing::new(value))
+    }
+}
+
+impl<T: PasswordHasher> TryFrom<Zeroizing<String>> for Password<T> {
+    type Error = Error;
+
+    fn try_from(value: Zeroizing<String>) -> Result<Self, Self::Error> {
+        Self::from_plaintext(&value, &PasswordPolicy::default())
+    }
+}
+
+/// Serializing a password always fails, to guarantee that the hash is never emitted.
+///
+/// Skip password fields when deriving `Serialize` for structs containing them,
+/// e.g. with `#[serde(skip)]`.
+#[cfg(feature = "serde")]
+impl<T: PasswordHasher> serde::Serialize for Password<T> {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom(
+            "passwords must not be serialized",
+        ))
+    }
+}
+
+impl<T: PasswordHasher> std::fmt::Display for Password<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
 impl<T: PasswordHasher> Password<T> {
     /// Initializes a new password instance.
     ///
+    /// The value is only borrowed, so an owned `String` isn't wiped,
+    /// use `TryFrom<String>` for that.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: impl AsRef<str>) -> Result<Self, Error> {
+        Self::new_with_policy(value, &PasswordPolicy::default())
+    }
+
+    /// Initializes a new password instance validated against the provided policy.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new_with_policy(value: impl AsRef<str>, policy: &PasswordPolicy) -> Result<Self, Error> {
+        Self::from_plaintext(value.as_ref(), policy)
+    }
+
+    /// Initializes a new password instance, that is validated against the provided
+    /// policy and checked against the HaveIBeenPwned breach corpus.
+    ///
+    /// Returns `ValidationError::BreachedPassword` if the password is known to be breached.
+    #[cfg(feature = "hibp")]
+    pub fn new_checked(
+        value: impl AsRef<str>,
+        policy: &PasswordPolicy,
+        client: &impl crate::hibp::RangeClient,
+    ) -> Result<Self, Error> {
+        Self::normalized(value.as_ref(), |value| {
+            Self::validate_value(value, policy)?;
+            if crate::hibp::is_breached(value, client)? {
+                return Err(ValidationError::BreachedPassword.into());
+            }
+            let password_hash = T::hash(value)?;
+            Ok(Self(password_hash, PhantomData))
+        })
+    }
+
+    /// Initializes a new password instance, hashing on the tokio blocking thread pool.
+    ///
     /// Returns a validation error if validation of the provided value fails.
-    pub fn new(value: &'static str) -> Result<Self, Error> {
-        Self::validate_value(value)?;
-        let password_hash = T::hash(value)?;
-        Ok(Self(password_hash.to_string(), PhantomData))
+    #[cfg(feature = "tokio")]
+    pub async fn new_async(value: impl AsRef<str>) -> Result<Self, Error>
+    where
+        T: crate::traits::AsyncPasswordHasher,
+    {
+        let value = Zeroizing::new(value.as_ref().nfc().collect::<String>());
+        Self::validate_value(&value, &PasswordPolicy::default())?;
+        let password_hash = T::hash_async(&value).await?;
+        Ok(Self(password_hash, PhantomData))
+    }
+
+    /// Initializes a new password instance validated against the provided policy
+    /// and hashed with the provided hasher's configuration.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new_with_hasher(
+        value: impl AsRef<str>,
+        policy: &PasswordPolicy,
+        hasher: &T,
+    ) -> Result<Self, Error>
+    where
+        T: ConfiguredPasswordHasher,
+    {
+        Self::normalized(value.as_ref(), |value| {
+            Self::validate_value(value, policy)?;
+            let password_hash = hasher.hash_password(value)?;
+            Ok(Self(password_hash, PhantomData))
+        })
+    }
+
+    /// Initializes a password instance wrapping an existing hash, e.g. loaded from storage.
+    ///
+    /// The hash is neither validated nor rehashed, a malformed hash surfaces as
+    /// `Error::MalformedHash` on confirmation.
+    pub fn from_hash(hash: String) -> Self {
+        Self(hash, PhantomData)
+    }
+
+    /// Returns the password hash for storage.
+    pub fn hash(&self) -> &str {
+        &self.0
+    }
+
+    fn from_plaintext(value: &str, policy: &PasswordPolicy) -> Result<Self, Error> {
+        Self::normalized(value, |value| {
+            Self::validate_value(value, policy)?;
+            let password_hash = T::hash(value)?;
+            Ok(Self(password_hash, PhantomData))
+        })
     }
 
-    fn validate_value(value: &str) -> Result<(), Error> {
-        let re = Regex::new(
-            r"^(?=.*\d)(?=.*[a-z])(?=.*[A-Z])(?=.*[#$%/()=¿?*+-])(?=(?:([\w\d])\1?(?!\1\1)))(?!(?=.*(palabra1|palabra2|palabraN))).{8,20}$",
-        )?;
-        if !re.is_match(value).unwrap_or(false) {
-            return Err(ValidationError::Password.into());
+    /// Calls the provided function with the value in Unicode normalization form C.
+    ///
+    /// Only values that aren't in NFC yet are copied, the copy is wiped on drop.
+    fn normalized<R>(value: &str, f: impl FnOnce(&str) -> R) -> R {
+        match is_nfc(value) {
+            true => f(value),
+            false => f(&Zeroizing::new(value.nfc().collect::<String>())),
+        }
+    }
+
+    /// Validates the provided value against the policy rules one by one.
+    ///
+    /// Returns a validation error for the first rule that fails.
+    fn validate_value(value: &str, policy: &PasswordPolicy) -> Result<(), Error> {
+        match Self::violations(value, policy).first() {
+            Some(&err) => Err(err.into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Validates the provided value against all policy rules.
+    ///
+    /// Returns every rule that fails, which is useful to drive checklists in front-ends.
+    ///
+    /// ```rust
+    /// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{Password, PasswordPolicy, PasswordValidationError}};
+    /// assert_eq!(
+    ///     Password::<Argon2PasswordHasher>::validate_all("abcDef", &PasswordPolicy::default()),
+    ///     Err(vec![
+    ///         PasswordValidationError::TooShort,
+    ///         PasswordValidationError::MissingDigit,
+    ///         PasswordValidationError::MissingSymbol,
+    ///     ])
+    /// );
+    /// ```
+    pub fn validate_all(
+        value: &str,
+        policy: &PasswordPolicy,
+    ) -> Result<(), Vec<PasswordValidationError>> {
+        let violations = Self::normalized(value, |value| Self::violations(value, policy));
+        if !violations.is_empty() {
+            return Err(violations);
         }
         Ok(())
     }
 
+    fn violations(value: &str, policy: &PasswordPolicy) -> Vec<PasswordValidationError> {
+        if value.is_empty() {
+            return vec![PasswordValidationError::Empty];
+        }
+        let mut violations = Vec::new();
+
+        let len = value.chars().count();
+        if len < policy.min_len {
+            violations.push(PasswordValidationError::TooShort);
+        }
+        if len > policy.max_len.min(MAX_LEN_LIMIT) {
+            violations.push(PasswordValidationError::TooLong);
+        }
+
+        if has_repeated_run(value) {
+            violations.push(PasswordValidationError::Repetitive);
+        }
+
+        if policy.require_upper && !value.chars().any(|c| c.is_ascii_uppercase()) {
+            violations.push(PasswordValidationError::MissingUppercase);
+        }
+        if policy.require_lower && !value.chars().any(|c| c.is_ascii_lowercase()) {
+            violations.push(PasswordValidationError::MissingLowercase);
+        }
+        if policy.require_digit && !value.chars().any(|c| c.is_ascii_digit()) {
+            violations.push(PasswordValidationError::MissingDigit);
+        }
+        if policy.require_symbol && !value.chars().any(|c| PASSWORD_SYMBOLS.contains(c)) {
+            violations.push(PasswordValidationError::MissingSymbol);
+        }
+
+        let lowercase = Zeroizing::new(value.to_lowercase());
+        if policy
+            .forbidden_words
+            .iter()
+            .any(|word| lowercase.contains(&word.to_lowercase()))
+        {
+            violations.push(PasswordValidationError::ForbiddenWord);
+        }
+        violations
+    }
+
     /// Confirms whehter the provided password matches the stored password hash.
     ///
-    /// Returns `Error::InvalidPassword` if the provided password is invalid.
+    /// Returns `Error::InvalidPassword` if the provided password is invalid,
+    /// or `Error::MalformedHash` if the stored hash can't be parsed.
+    /// An empty password is always invalid and never reaches the hasher.
+    ///
+    /// Takes a data-independent amount of time with respect to the correctness
+    /// of the password, as guaranteed by the `PasswordHasher` implementation.
     pub fn confirm(&self, password: &str) -> Result<(), Error> {
-        T::confirm_password(password, &self.0.clone())?;
+        Self::reject_empty(password)?;
+        Self::normalized(password, |password| T::confirm_password(password, &self.0))?;
         Ok(())
     }
+
+    /// Confirms whehter the provided password matches the stored password hash
+    /// using the provided hasher's configuration, e.g. its secret.
+    ///
+    /// Returns `Error::InvalidPassword` if the provided password is invalid.
+    pub fn confirm_with(&self, password: &str, hasher: &T) -> Result<(), Error>
+    where
+        T: ConfiguredPasswordHasher,
+    {
+        Self::reject_empty(password)?;
+        Self::normalized(password, |password| {
+            hasher.verify_password(password, &self.0)
+        })?;
+        Ok(())
+    }
+
+    /// Confirms whehter the provided password matches the stored password hash,
+    /// verifying on the tokio blocking thread pool.
+    ///
+    /// Returns `Error::InvalidPassword` if the provided password is invalid.
+    #[cfg(feature = "tokio")]
+    pub async fn confirm_async(&self, password: &str) -> Result<(), Error>
+    where
+        T: crate::traits::AsyncPasswordHasher,
+    {
+        Self::reject_empty(password)?;
+        let password = Zeroizing::new(password.nfc().collect::<String>());
+        T::confirm_password_async(&password, &self.0).await?;
+        Ok(())
+    }
+
+    /// Rejects empty input on confirmation, so it can't match by accident,
+    /// e.g. against a hash created without validation.
+    fn reject_empty(password: &str) -> Result<(), Error> {
+        match password.is_empty() {
+            true => Err(Error::InvalidPassword),
+            false => Ok(()),
+        }
+    }
+
+    /// Confirms whether the stored password hash should be rehashed, because
+    /// it was created with weaker settings than the hasher currently targets.
+    ///
+    /// Best checked after a successful `confirm`, while the plaintext is still available.
+    pub fn needs_rehash(&self) -> Result<bool, Error> {
+        Ok(T::needs_rehash(&self.0)?)
+    }
+}
+
+/// Indicates the estimated strength of a password.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Strength {
+    /// Less than 40 bits of estimated entropy.
+    Weak,
+
+    /// Between 40 and 64 bits of estimated entropy.
+    Fair,
+
+    /// Between 64 and 96 bits of estimated entropy.
+    Strong,
+
+    /// 96 bits of estimated entropy or more.
+    VeryStrong,
+}
+
+/// Returns the estimated entropy of the provided value in bits.
+///
+/// The estimate is based on the size of the character pool in use (lowercase,
+/// uppercase, digits, symbols and other characters) and the length of the value,
+/// where characters repeating their predecessor don't add to the length.
+pub fn entropy(value: &str) -> f64 {
+    let mut pool = 0;
+    if value.chars().any(|c| c.is_ascii_lowercase()) {
+        pool += 26;
+    }
+    if value.chars().any(|c| c.is_ascii_uppercase()) {
+        pool += 26;
+    }
+    if value.chars().any(|c| c.is_ascii_digit()) {
+        pool += 10;
+    }
+    if value
+        .chars()
+        .any(|c| c.is_ascii() && !c.is_ascii_alphanumeric())
+    {
+        pool += 33;
+    }
+    if !value.is_ascii() {
+        pool += 100;
+    }
+    if pool == 0 {
+        return 0.0;
+    }
+
+    let mut previous = None;
+    let mut len = 0;
+    for c in value.chars() {
+        if previous != Some(c) {
+            len += 1;
+        }
+        previous = Some(c);
+    }
+
+    len as f64 * f64::from(pool).log2()
+}
+
+/// Returns the estimated strength of the provided value.
+///
+/// Doesn't require the value to pass validation, so it can be used for live strength meters.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::password::{strength, Strength};
+/// assert_eq!(strength("password"), Strength::Weak);
+/// assert_eq!(strength("mmholAhsbC123*"), Strength::Strong);
+/// ```
+pub fn strength(value: &str) -> Strength {
+    match entropy(value) {
+        e if e < 40.0 => Strength::Weak,
+        e if e < 64.0 => Strength::Fair,
+        e if e < 96.0 => Strength::Strong,
+        _ => Strength::VeryStrong,
+    }
 }
 
 #[cfg(test)]
@@ -82,62 +509,422 @@ mod tests {
         // Ensure that a password shorter than 8 characters fails validation.
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("aQ3*"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
         );
 
-        // Ensure that a password longer than 20 characters fails validation.
+        // Ensure that a long passphrase passes.
+        let passphrase = "Correct-Horse-Battery-Staple-Blue-42-Sky";
+        assert_eq!(passphrase.len(), 40);
+        assert!(Password::<Argon2PasswordHasher>::new(passphrase).is_ok());
+
+        // Ensure that a password longer than 128 characters fails validation.
         assert_eq!(
-            Password::<Argon2PasswordHasher>::new("mmholAhsbC123*artfgrr"),
-            Err(Error::Validation(ValidationError::Password))
+            Password::<Argon2PasswordHasher>::new("mmholAhsbC123*".repeat(10)),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooLong
+            )))
         );
 
         // Ensure that repetitive passwords fail validation.
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("aaaaaaaaaaaaaaaaaaa"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::Repetitive
+            )))
         );
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("AAAAAAAAAAAAAAAAAAA"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::Repetitive
+            )))
         );
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("1111111111111111111"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::Repetitive
+            )))
         );
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("*******************"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::Repetitive
+            )))
         );
 
         // Ensure that a password without at least one symbol fails validation.
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("mmholAhsbC123"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::MissingSymbol
+            )))
         );
 
         // Ensure that a password without at least one capital letter fails validation.
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("mmholahsbc123*"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::MissingUppercase
+            )))
         );
 
         // Ensure that a password without at least one lowercase letter fails validation.
         assert_eq!(
             Password::<Argon2PasswordHasher>::new("MMHOLAHSBC123*"),
-            Err(Error::Validation(ValidationError::Password))
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::MissingLowercase
+            )))
         );
 
         // Ensure that a password without at least one digit fails validation.
         assert_eq!(
-            Password::<Argon2PasswordHasher>::new("mmholAhsbCaaa*"),
-            Err(Error::Validation(ValidationError::Password))
+            Password::<Argon2PasswordHasher>::new("mmholAhsbCabc*"),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::MissingDigit
+            )))
         );
     }
 
+    #[test]
+    fn password_policy_rules_work() {
+        let new = |value, policy: &PasswordPolicy| {
+            Password::<Argon2PasswordHasher>::new_with_policy(value, policy)
+        };
+        let invalid = |e| Err(Error::Validation(ValidationError::Password(e)));
+        let validate = Password::<Argon2PasswordHasher>::validate_all;
+
+        let policy = PasswordPolicy {
+            min_len: 4,
+            ..Default::default()
+        };
+        assert!(new("aQ3*", &policy).is_ok());
+        assert_eq!(
+            new("aQ3", &policy),
+            invalid(PasswordValidationError::TooShort)
+        );
+
+        let policy = PasswordPolicy {
+            max_len: 30,
+            ..Default::default()
+        };
+        assert!(new("mmholAhsbC123*artfgrr", &policy).is_ok());
+        assert_eq!(
+            new("mmholAhsbC123*artfgrrmmholAhsbC", &policy),
+            invalid(PasswordValidationError::TooLong)
+        );
+
+        // Ensure that the hard limit applies regardless of the policy.
+        let policy = PasswordPolicy {
+            max_len: usize::MAX,
+            ..Default::default()
+        };
+        let value = "mmholAhsbC123*".repeat(MAX_LEN_LIMIT / 14);
+        assert!(validate(&value, &policy).is_ok());
+        assert_eq!(
+            validate(&format!("{value}mmholAhsbC123*"), &policy),
+            Err(vec![PasswordValidationError::TooLong])
+        );
+
+        let policy = PasswordPolicy {
+            require_upper: false,
+            ..Default::default()
+        };
+        assert!(new("mmholahsbc123*", &policy).is_ok());
+        assert_eq!(
+            new("mmholahsbc*", &policy),
+            invalid(PasswordValidationError::MissingDigit)
+        );
+
+        let policy = PasswordPolicy {
+            require_lower: false,
+            ..Default::default()
+        };
+        assert!(new("MMHOLAHSBC123*", &policy).is_ok());
+        assert_eq!(
+            new("MMHOLAHSBC*", &policy),
+            invalid(PasswordValidationError::MissingDigit)
+        );
+
+        let policy = PasswordPolicy {
+            require_digit: false,
+            ..Default::default()
+        };
+        assert!(new("mmholAhsbCabc*", &policy).is_ok());
+        assert_eq!(
+            new("mmholAhsbCabc", &policy),
+            invalid(PasswordValidationError::MissingSymbol)
+        );
+
+        let policy = PasswordPolicy {
+            require_symbol: false,
+            ..Default::default()
+        };
+        assert!(new("mmholAhsbC123", &policy).is_ok());
+        assert_eq!(
+            new("mmholahsbc123", &policy),
+            invalid(PasswordValidationError::MissingUppercase)
+        );
+    }
+
+    #[test]
+    fn password_repetition_rule_works() {
+        let policy = PasswordPolicy::default();
+        for value in ["aaA1*bcd", "aA1*bbcc", "mmholAhsbC123*", "aAaA1*1*"] {
+            assert_eq!(
+                Password::<Argon2PasswordHasher>::validate_all(value, &policy),
+                Ok(()),
+                "{value}"
+            );
+        }
+
+        for value in ["aaa1*Bcd", "aB1*cddd", "aB1***cd", "aB11114*"] {
+            assert_eq!(
+                Password::<Argon2PasswordHasher>::validate_all(value, &policy),
+                Err(vec![PasswordValidationError::Repetitive]),
+                "{value}"
+            );
+        }
+
+        assert!(!has_repeated_run(""));
+        assert!(!has_repeated_run("aa"));
+        assert!(has_repeated_run("ééé"));
+    }
+
+    #[test]
+    fn password_validate_all_works() {
+        let policy = PasswordPolicy::default();
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::validate_all("mmholAhsbC123*", &policy),
+            Ok(())
+        );
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::validate_all("abcDef", &policy),
+            Err(vec![
+                PasswordValidationError::TooShort,
+                PasswordValidationError::MissingDigit,
+                PasswordValidationError::MissingSymbol,
+            ])
+        );
+
+        // Ensure that new still fails with the first violation only.
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::new("abcDef"),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
+        );
+    }
+
+    #[test]
+    fn password_policy_forbidden_words_work() {
+        let policy = PasswordPolicy {
+            forbidden_words: vec!["Sonemas".to_string(), "password".to_string()],
+            ..Default::default()
+        };
+        assert!(
+            Password::<Argon2PasswordHasher>::new_with_policy("mmholAhsbC123*", &policy).is_ok()
+        );
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::new_with_policy("soNEMas123*A", &policy),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::ForbiddenWord
+            )))
+        );
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::new_with_policy("MyPassword1*", &policy),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::ForbiddenWord
+            )))
+        );
+
+        // Ensure that the default policy doesn't forbid any words.
+        assert!(Password::<Argon2PasswordHasher>::new("MyPassword1*").is_ok());
+    }
+
+    #[test]
+    fn password_strength_works() {
+        assert_eq!(entropy(""), 0.0);
+        assert_eq!(strength(""), Strength::Weak);
+        assert_eq!(strength("aaaaaaaaaaaaaaaaaaa"), Strength::Weak);
+        assert_eq!(strength("password"), Strength::Weak);
+        assert_eq!(strength("Tr0ub4dor"), Strength::Fair);
+        assert_eq!(strength("mmholAhsbC123*"), Strength::Strong);
+        assert_eq!(
+            strength("correct horse battery staple"),
+            Strength::VeryStrong
+        );
+
+        // Ensure that repetition doesn't add strength.
+        assert_eq!(entropy("aaaa"), entropy("a"));
+        assert!(strength("mmholAhsbC123*") > strength("mmholahsbc"));
+    }
+
+    #[cfg(feature = "hibp")]
+    #[test]
+    fn password_new_checked_works() {
+        use crate::hibp::tests::MockClient;
+
+        // SHA-1 of "mmholAhsbC123*" is 80A840D27558E2A4AC6D25A069A3EA70CA6B52ED.
+        let policy = PasswordPolicy::default();
+        let client = MockClient("0D27558E2A4AC6D25A069A3EA70CA6B52ED:12");
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::new_checked("mmholAhsbC123*", &policy, &client),
+            Err(Error::Validation(ValidationError::BreachedPassword))
+        );
+
+        let client = MockClient("003D68EB55068C33ACE09247EE4C639306B:3");
+        assert!(
+            Password::<Argon2PasswordHasher>::new_checked("mmholAhsbC123*", &policy, &client)
+                .is_ok()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn password_serialize_fails() {
+        let password = Password::<Argon2PasswordHasher>::new("mmholAhsbC123*").unwrap();
+        assert!(serde_json::to_string(&password).is_err());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn password_async_works() {
+        let password = Password::<Argon2PasswordHasher>::new_async("mmholAhsbC123*")
+            .await
+            .unwrap();
+        assert!(password.confirm_async("mmholAhsbC123*").await.is_ok());
+        assert_eq!(
+            password.confirm_async("blabla").await,
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::new_async("aQ3*").await,
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
+        );
+    }
+
+    #[test]
+    fn password_unicode_normalization_works() {
+        // "Café" with a precomposed `é` (NFC) and with `e` and a combining accent (NFD).
+        let nfc = "Caf\u{e9}Abc123*";
+        let nfd = "Cafe\u{301}Abc123*";
+        assert_ne!(nfc, nfd);
+
+        let password = Password::<Argon2PasswordHasher>::new(nfd).unwrap();
+        assert!(password.confirm(nfc).is_ok());
+        assert!(password.confirm(nfd).is_ok());
+
+        let password = Password::<Argon2PasswordHasher>::new(nfc).unwrap();
+        assert!(password.confirm(nfd).is_ok());
+        assert!(password.confirm("CafeAbc123*").is_err());
+
+        // Ensure that the length is counted after normalization.
+        let policy = PasswordPolicy {
+            max_len: 11,
+            ..Default::default()
+        };
+        assert!(Password::<Argon2PasswordHasher>::validate_all(nfd, &policy).is_ok());
+    }
+
     #[test]
     fn password_confirmation_works() {
         let password = Password::<Argon2PasswordHasher>::new("mmholAhsbC123*").unwrap();
         assert!(password.confirm("mmholAhsbC123*").is_ok());
         assert_eq!(password.confirm("blabla"), Err(Error::InvalidPassword));
     }
+
+    #[test]
+    fn empty_password_is_rejected() {
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::new(""),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::Empty
+            )))
+        );
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::validate_all("", &PasswordPolicy::default()),
+            Err(vec![PasswordValidationError::Empty])
+        );
+
+        // Ensure that an empty password never matches, not even an empty hashed password.
+        let password = Password::<Argon2PasswordHasher>::new("mmholAhsbC123*").unwrap();
+        assert_eq!(password.confirm(""), Err(Error::InvalidPassword));
+        let hash = Argon2PasswordHasher::hash("").unwrap();
+        let password = Password::<Argon2PasswordHasher>::from_hash(hash);
+        assert_eq!(password.confirm(""), Err(Error::InvalidPassword));
+    }
+
+    #[test]
+    fn password_from_runtime_string_works() {
+        let value = ["mmholAhsbC", "123*"].concat();
+        let password = Password::<Argon2PasswordHasher>::new(&value).unwrap();
+        assert!(password.confirm(&value).is_ok());
+
+        let password = Password::<Argon2PasswordHasher>::try_from(value.as_str()).unwrap();
+        assert!(password.confirm(&value).is_ok());
+
+        let password = Password::<Argon2PasswordHasher>::try_from(value.clone()).unwrap();
+        assert!(password.confirm(&value).is_ok());
+
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::try_from(String::from("aaa")),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
+        );
+    }
+
+    #[test]
+    fn password_from_zeroizing_works() {
+        let value = Zeroizing::new(String::from("mmholAhsbC123*"));
+        let password = Password::<Argon2PasswordHasher>::try_from(value).unwrap();
+        assert!(password.confirm("mmholAhsbC123*").is_ok());
+
+        let value = Zeroizing::new(String::from("aaa"));
+        assert_eq!(
+            Password::<Argon2PasswordHasher>::try_from(value),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
+        );
+    }
+
+    #[test]
+    fn password_needs_rehash_works() {
+        let password = Password::<Argon2PasswordHasher>::new("mmholAhsbC123*").unwrap();
+        assert_eq!(password.needs_rehash(), Ok(false));
+    }
+
+    #[test]
+    fn malformed_hash_is_distinguished() {
+        let password = Password::<Argon2PasswordHasher>("garbage".to_string(), PhantomData);
+        assert_eq!(
+            password.confirm("mmholAhsbC123*"),
+            Err(Error::MalformedHash)
+        );
+
+        // Ensure that a wrong password against a valid hash still is an invalid password.
+        let password = Password::<Argon2PasswordHasher>::new("mmholAhsbC123*").unwrap();
+        assert_eq!(password.confirm("blabla"), Err(Error::InvalidPassword));
+    }
+
+    #[test]
+    fn hash_round_trip_works() {
+        let password = Password::<Argon2PasswordHasher>::new("mmholAhsbC123*").unwrap();
+        assert!(password.hash().starts_with("$argon2id$"));
+
+        let stored = password.hash().to_string();
+        let loaded = Password::<Argon2PasswordHasher>::from_hash(stored.clone());
+        assert_eq!(loaded, password);
+        assert_eq!(loaded.hash(), stored);
+        assert!(loaded.confirm("mmholAhsbC123*").is_ok());
+        assert_eq!(loaded.confirm("blabla"), Err(Error::InvalidPassword));
+
+        // Ensure that the stored hash isn't validated as a plaintext password.
+        let loaded = Password::<Argon2PasswordHasher>::from_hash("aaa".to_string());
+        assert_eq!(loaded.hash(), "aaa");
+    }
 }
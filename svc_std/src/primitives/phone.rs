@@ -0,0 +1,144 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+
+/// Maximum number of digits of a phone number, as per E.164.
+pub const MAX_DIGITS: usize = 15;
+
+/// A validatable phone number field in E.164 format.
+///
+/// Spaces, dashes and parentheses are stripped on storage,
+/// so `+1 (415) 555-2671` is stored as `+14155552671`.
+///
+/// ```rust
+/// # use crate::svc_std::{traits::Validatable, primitives::{Error, PhoneNumber, ValidationError}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let phone = PhoneNumber::new("+1 (415) 555-2671")?;
+///     assert!(phone.validate().is_ok());
+///     assert_eq!(phone.to_string(), "+14155552671");
+///     assert_eq!(PhoneNumber::new("4155552671"), Err(Error::Validation(ValidationError::PhoneNumber)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PhoneNumber(String);
+
+impl Validatable<Error> for PhoneNumber {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        let digits = match self.0.strip_prefix('+') {
+            Some(digits) => digits,
+            None => return Err(ValidationError::PhoneNumber.into()),
+        };
+
+        // Country codes never start with a zero.
+        if !(1..=MAX_DIGITS).contains(&digits.len())
+            || !digits.bytes().all(|b| b.is_ascii_digit())
+            || digits.starts_with('0')
+        {
+            return Err(ValidationError::PhoneNumber.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for PhoneNumber {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for PhoneNumber {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        PhoneNumber::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PhoneNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        PhoneNumber::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl PhoneNumber {
+    /// Initializes a new phone number instance.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(Self::normalize(value));
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the provided value without spaces, dashes and parentheses.
+    fn normalize(value: &str) -> String {
+        value
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '(' | ')'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phone_number_validation_works() {
+        assert!(PhoneNumber::new("+14155552671").is_ok());
+        assert!(PhoneNumber::new("+442071838750").is_ok());
+        assert!(PhoneNumber::new(&format!("+{}", "1".repeat(MAX_DIGITS))).is_ok());
+
+        for value in [
+            "",
+            "+",
+            "4155552671",
+            "+0155552671",
+            "+1415555267a",
+            "+1.415.555.2671",
+            "++14155552671",
+            &format!("+{}", "1".repeat(MAX_DIGITS + 1)),
+        ] {
+            assert_eq!(
+                PhoneNumber::new(value),
+                Err(Error::Validation(ValidationError::PhoneNumber)),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn phone_number_normalization_works() {
+        let phone = PhoneNumber::new("+1 (415) 555-2671").unwrap();
+        assert_eq!(phone, PhoneNumber::new("+14155552671").unwrap());
+        assert_eq!(phone.to_string(), "+14155552671");
+
+        let phone: PhoneNumber = "+44 20 7183 8750".try_into().unwrap();
+        assert_eq!(phone.to_string(), "+442071838750");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn phone_number_serde_works() {
+        let phone = PhoneNumber::new("+14155552671").unwrap();
+        let json = serde_json::to_string(&phone).unwrap();
+        assert_eq!(json, r#""+14155552671""#);
+        assert_eq!(serde_json::from_str::<PhoneNumber>(&json).unwrap(), phone);
+        assert!(serde_json::from_str::<PhoneNumber>(r#""4155552671""#).is_err());
+    }
+}
@@ -0,0 +1,244 @@
+use super::{
+    error::{Error, ValidationError},
+    CountryCode,
+};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+use fancy_regex::Regex;
+use std::{collections::HashMap, sync::OnceLock};
+
+/// Postal code format of a country.
+pub struct PostalFormat {
+    /// ISO 3166-1 alpha-2 code of the country.
+    pub country: &'static str,
+
+    /// Pattern the normalized, uppercase code must match.
+    pub pattern: &'static str,
+
+    /// Number of trailing characters separated by a space, e.g. 3 for `SW1A 1AA`.
+    /// The space is optional on input and always present after normalization.
+    pub split: Option<usize>,
+}
+
+/// Known postal code formats, add a row to support another country.
+pub const FORMATS: &[PostalFormat] = &[
+    PostalFormat {
+        country: "CA",
+        pattern: r"^[ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z] \d[ABCEGHJ-NPRSTV-Z]\d$",
+        split: Some(3),
+    },
+    PostalFormat {
+        country: "DE",
+        pattern: r"^\d{5}$",
+        split: None,
+    },
+    PostalFormat {
+        country: "FR",
+        pattern: r"^\d{5}$",
+        split: None,
+    },
+    PostalFormat {
+        country: "GB",
+        pattern: r"^(GIR 0AA|[A-Z]{1,2}\d[A-Z\d]? \d[A-Z]{2})$",
+        split: Some(3),
+    },
+    PostalFormat {
+        country: "NL",
+        pattern: r"^[1-9]\d{3} [A-Z]{2}$",
+        split: Some(2),
+    },
+    PostalFormat {
+        country: "US",
+        pattern: r"^\d{5}(-\d{4})?$",
+        split: None,
+    },
+];
+
+/// Lenient pattern for countries without a known format.
+const FALLBACK_PATTERN: &str = r"^[A-Z\d][A-Z\d -]{0,8}[A-Z\d]$";
+
+fn postal_regexes() -> &'static HashMap<&'static str, Regex> {
+    static POSTAL_REGEXES: OnceLock<HashMap<&'static str, Regex>> = OnceLock::new();
+    POSTAL_REGEXES.get_or_init(|| {
+        FORMATS
+            .iter()
+            .map(|format| (format.country, format.pattern))
+            .chain([("", FALLBACK_PATTERN)])
+            .map(|(country, pattern)| {
+                let regex = Regex::new(pattern).expect("postal code pattern is valid");
+                (country, regex)
+            })
+            .collect()
+    })
+}
+
+fn format(country: &CountryCode) -> Option<&'static PostalFormat> {
+    FORMATS
+        .iter()
+        .find(|format| format.country == country.as_str())
+}
+
+/// A validatable postal code of a country.
+///
+/// Codes are uppercased and validated against the country's format in `FORMATS`,
+/// other countries are checked against a lenient pattern.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{CountryCode, Error, PostalCode, ValidationError};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let code = PostalCode::new(CountryCode::new("GB")?, "sw1a1aa")?;
+///     assert_eq!(code.as_str(), "SW1A 1AA");
+///     assert_eq!(
+///         PostalCode::new(CountryCode::new("US")?, "1234"),
+///         Err(Error::Validation(ValidationError::PostalCode))
+///     );
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PostalCode {
+    country: CountryCode,
+    code: String,
+}
+
+impl Validatable<Error> for PostalCode {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        let key = format(&self.country).map_or("", |format| format.country);
+        let regex = &postal_regexes()[key];
+        if !regex.is_match(&self.code)? {
+            return Err(ValidationError::PostalCode.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for PostalCode {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PostalCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Raw {
+            country: CountryCode,
+            code: String,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        PostalCode::new(raw.country, &raw.code).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+impl PostalCode {
+    /// Initializes a new postal code instance for the provided country.
+    ///
+    /// The code is trimmed and uppercased, and for formats with a split
+    /// the separating space is inserted if it's missing.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(country: CountryCode, code: &str) -> Result<Self, Error> {
+        let mut code = code.trim().to_uppercase();
+        if let Some(split) = format(&country).and_then(|format| format.split) {
+            code.retain(|c| c != ' ');
+            if let Some(at) = code.len().checked_sub(split).filter(|&at| at > 0) {
+                if code.is_char_boundary(at) {
+                    code.insert(at, ' ');
+                }
+            }
+        }
+
+        let v = Self { country, code };
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the country of the postal code.
+    pub fn country(&self) -> &CountryCode {
+        &self.country
+    }
+
+    /// Returns the normalized postal code as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn postal_code(country: &str, code: &str) -> Result<PostalCode, Error> {
+        PostalCode::new(CountryCode::new(country).unwrap(), code)
+    }
+
+    #[test]
+    fn postal_formats_are_valid() {
+        assert_eq!(postal_regexes().len(), FORMATS.len() + 1);
+        for format in FORMATS {
+            assert!(
+                CountryCode::new(format.country).is_ok(),
+                "{}",
+                format.country
+            );
+        }
+    }
+
+    #[test]
+    fn postal_code_validation_works() {
+        for (country, code, normalized) in [
+            ("US", "12345", "12345"),
+            ("US", "12345-6789", "12345-6789"),
+            ("DE", " 10115 ", "10115"),
+            ("GB", "SW1A 1AA", "SW1A 1AA"),
+            ("GB", "sw1a1aa", "SW1A 1AA"),
+            ("GB", "M1 1AE", "M1 1AE"),
+            ("CA", "k1a0b1", "K1A 0B1"),
+            ("NL", "1234ab", "1234 AB"),
+            ("BE", "1000", "1000"),
+        ] {
+            let postal_code = postal_code(country, code).unwrap();
+            assert_eq!(postal_code.as_str(), normalized, "{country} {code}");
+            assert_eq!(postal_code.country().as_str(), country);
+        }
+
+        for (country, code) in [
+            ("US", "1234"),
+            ("US", "12345-67"),
+            ("DE", "1011A"),
+            ("GB", "SW1A"),
+            ("GB", "1AA SW1"),
+            ("CA", "D1A 0B1"),
+            ("NL", "0123 AB"),
+            ("BE", ""),
+            ("BE", "1000!"),
+        ] {
+            assert_eq!(
+                postal_code(country, code),
+                Err(Error::Validation(ValidationError::PostalCode)),
+                "{country} {code}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn postal_code_serde_works() {
+        let postal_code = postal_code("GB", "SW1A 1AA").unwrap();
+        let json = serde_json::to_string(&postal_code).unwrap();
+        assert_eq!(json, r#"{"country":"GB","code":"SW1A 1AA"}"#);
+        assert_eq!(
+            serde_json::from_str::<PostalCode>(r#"{"country":"gb","code":"sw1a1aa"}"#).unwrap(),
+            postal_code
+        );
+        assert!(serde_json::from_str::<PostalCode>(r#"{"country":"US","code":"1"}"#).is_err());
+    }
+}
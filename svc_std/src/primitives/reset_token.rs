@@ -0,0 +1,162 @@
+use super::{DateTime, Error};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use data_encoding::BASE64URL_NOPAD;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+
+/// Number of random bytes in a token.
+pub const TOKEN_BYTES: usize = 32;
+
+/// A single-use, time-limited password reset token.
+///
+/// Only the SHA-256 hash of the token is stored, the token itself is returned
+/// once on generation to be sent to the user. A slow password hash isn't needed,
+/// because the token has 256 bits of entropy.
+///
+/// Redeeming consumes the token, the caller is expected to delete the stored
+/// token afterwards.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{DateTime, Error, ResetToken};
+/// # use std::time::Duration;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let expires = DateTime::now().checked_add(Duration::from_secs(15 * 60)).unwrap();
+///     let (reset_token, token) = ResetToken::generate(expires);
+///     // Store `reset_token.hash()` and `reset_token.expires()`, send `token` to the user.
+///
+///     let stored = ResetToken::from_hash(reset_token.hash(), reset_token.expires());
+///     assert!(stored.redeem(&token, DateTime::now()).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResetToken {
+    hash: String,
+    expires: DateTime,
+}
+
+impl ResetToken {
+    /// Generates a new token that expires at the provided time.
+    ///
+    /// Returns the reset token and the URL-safe base64 encoded token,
+    /// which can't be recovered afterwards.
+    pub fn generate(expires: DateTime) -> (Self, Zeroizing<String>) {
+        let mut bytes = Zeroizing::new([0u8; TOKEN_BYTES]);
+        OsRng.fill_bytes(bytes.as_mut());
+        let token = Zeroizing::new(BASE64URL_NOPAD.encode(bytes.as_ref()));
+
+        let reset_token = Self {
+            hash: Self::digest(&token),
+            expires,
+        };
+        (reset_token, token)
+    }
+
+    /// Initializes a reset token from a stored hash and expiry time.
+    pub fn from_hash(hash: impl Into<String>, expires: DateTime) -> Self {
+        Self {
+            hash: hash.into(),
+            expires,
+        }
+    }
+
+    /// Returns the hash of the token, which is safe to store.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Returns the time at which the token expires.
+    pub fn expires(&self) -> DateTime {
+        self.expires
+    }
+
+    /// Confirms whether the provided token matches and hasn't expired at the provided time.
+    ///
+    /// Returns `Error::ExpiredResetToken` if the token has expired, or
+    /// `Error::InvalidResetToken` if the provided token doesn't match.
+    /// The hashes are compared in constant time.
+    pub fn verify(&self, token: &str, now: DateTime) -> Result<(), Error> {
+        if now >= self.expires {
+            return Err(Error::ExpiredResetToken);
+        }
+
+        let hash = Self::digest(token);
+        match bool::from(hash.as_bytes().ct_eq(self.hash.as_bytes())) {
+            true => Ok(()),
+            false => Err(Error::InvalidResetToken),
+        }
+    }
+
+    /// Verifies the provided token and consumes the reset token, so it can only be used once.
+    ///
+    /// Returns the same errors as `ResetToken::verify`.
+    pub fn redeem(self, token: &str, now: DateTime) -> Result<(), Error> {
+        self.verify(token, now)
+    }
+
+    fn digest(token: &str) -> String {
+        BASE64URL_NOPAD.encode(&Sha256::digest(token.as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn expires() -> DateTime {
+        DateTime::now()
+            .checked_add(Duration::from_secs(15 * 60))
+            .unwrap()
+    }
+
+    #[test]
+    fn generate_and_verify_works() {
+        let (reset_token, token) = ResetToken::generate(expires());
+        assert_eq!(token.len(), 43);
+        assert!(token
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'));
+        assert!(!reset_token.hash().contains(token.as_str()));
+
+        let stored = ResetToken::from_hash(reset_token.hash(), reset_token.expires());
+        assert_eq!(stored, reset_token);
+        assert!(stored.verify(&token, DateTime::now()).is_ok());
+        assert!(stored.redeem(&token, DateTime::now()).is_ok());
+
+        // Ensure that tokens are random.
+        let (other, other_token) = ResetToken::generate(expires());
+        assert_ne!(other.hash(), reset_token.hash());
+        assert_ne!(other_token, token);
+    }
+
+    #[test]
+    fn wrong_token_fails() {
+        let (reset_token, token) = ResetToken::generate(expires());
+        let (_, other_token) = ResetToken::generate(expires());
+        for wrong in ["", "blabla", &other_token, &token[1..]] {
+            assert_eq!(
+                reset_token.verify(wrong, DateTime::now()),
+                Err(Error::InvalidResetToken)
+            );
+        }
+    }
+
+    #[test]
+    fn expired_token_fails() {
+        let expires = DateTime::from_unix_secs(1_700_000_000);
+        let (reset_token, token) = ResetToken::generate(expires);
+        assert!(reset_token
+            .verify(&token, DateTime::from_unix_secs(1_699_999_999))
+            .is_ok());
+        assert_eq!(
+            reset_token.verify(&token, expires),
+            Err(Error::ExpiredResetToken)
+        );
+        assert_eq!(
+            reset_token.redeem(&token, DateTime::now()),
+            Err(Error::ExpiredResetToken)
+        );
+    }
+}
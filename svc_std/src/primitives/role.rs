@@ -0,0 +1,269 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+use std::collections::{HashMap, HashSet};
+
+/// Maximum length of a role.
+pub const MAX_LEN: usize = 64;
+
+/// A validatable role used for authorization.
+///
+/// Roles consist of up to 64 lowercase ASCII letters, digits, underscores, hyphens
+/// and colons and must start with a letter, e.g. `admin` or `billing:read`.
+/// Input is normalized to lowercase.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{Error, Role, ValidationError};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let role = Role::new("Billing:Read")?;
+///     assert_eq!(role.as_str(), "billing:read");
+///     assert_eq!(Role::new("1admin"), Err(Error::Validation(ValidationError::Role)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Role(String);
+
+impl Validatable<Error> for Role {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        if self.0.len() > MAX_LEN
+            || !self.0.starts_with(|c: char| c.is_ascii_lowercase())
+            || !self.0.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | ':')
+            })
+        {
+            return Err(ValidationError::Role.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for Role {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for Role {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Role::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Role {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Role {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Role::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Role {
+    /// Initializes a new role instance.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(value.to_ascii_lowercase());
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the role as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A set of roles.
+///
+/// With the `serde` feature roles are serialized as a sorted list.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Roles(HashSet<Role>);
+
+impl Roles {
+    /// Initializes a new, empty set of roles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Confirms whether the set contains the provided role.
+    pub fn contains(&self, role: &Role) -> bool {
+        self.0.contains(role)
+    }
+
+    /// Adds the provided role, returns whether it wasn't present yet.
+    pub fn insert(&mut self, role: Role) -> bool {
+        self.0.insert(role)
+    }
+
+    /// Removes the provided role, returns whether it was present.
+    pub fn remove(&mut self, role: &Role) -> bool {
+        self.0.remove(role)
+    }
+
+    /// Returns an iterator over the roles in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &Role> {
+        self.0.iter()
+    }
+
+    /// Returns the number of roles.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Confirms whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<Role> for Roles {
+    fn from_iter<I: IntoIterator<Item = Role>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Validates every role, returning the first error.
+impl Validatable<Error> for Roles {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        self.0.iter().try_for_each(Validatable::validate)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Roles {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut roles: Vec<&Role> = self.0.iter().collect();
+        roles.sort();
+        serializer.collect_seq(roles)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Roles {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<Role>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+/// A mapping from roles to the permissions they grant.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::{role::RolePermissions, Role, Roles};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let permissions = RolePermissions::new()
+///         .with(Role::new("admin")?, ["orders:read", "orders:write"])
+///         .with(Role::new("support")?, ["orders:read"]);
+///     let roles: Roles = [Role::new("support")?].into_iter().collect();
+///     assert!(permissions.allows(&roles, "orders:read"));
+///     assert!(!permissions.allows(&roles, "orders:write"));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RolePermissions(HashMap<Role, HashSet<String>>);
+
+impl RolePermissions {
+    /// Initializes a new, empty mapping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the provided permissions to the role.
+    pub fn with<'a>(mut self, role: Role, permissions: impl IntoIterator<Item = &'a str>) -> Self {
+        self.0
+            .entry(role)
+            .or_default()
+            .extend(permissions.into_iter().map(str::to_string));
+        self
+    }
+
+    /// Confirms whether any of the provided roles grants the permission.
+    pub fn allows(&self, roles: &Roles, permission: &str) -> bool {
+        roles.iter().any(|role| {
+            self.0
+                .get(role)
+                .is_some_and(|permissions| permissions.contains(permission))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_validation_works() {
+        for value in [
+            "admin",
+            "billing:read",
+            "support_agent",
+            "team-1",
+            &"a".repeat(MAX_LEN),
+        ] {
+            assert!(Role::new(value).is_ok(), "{value}");
+        }
+        assert_eq!(Role::new("Admin").unwrap().as_str(), "admin");
+
+        for value in [
+            "",
+            "1admin",
+            ":admin",
+            "billing read",
+            "billing.read",
+            "rôle",
+            &"a".repeat(MAX_LEN + 1),
+        ] {
+            assert_eq!(
+                Role::new(value),
+                Err(Error::Validation(ValidationError::Role)),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn roles_work() {
+        let admin = Role::new("admin").unwrap();
+        let mut roles: Roles = [admin.clone()].into_iter().collect();
+        assert!(roles.contains(&admin));
+        assert!(!roles.insert(admin.clone()));
+        assert!(roles.insert(Role::new("support").unwrap()));
+        assert_eq!(roles.len(), 2);
+
+        assert!(roles.remove(&admin));
+        assert!(!roles.remove(&admin));
+        assert!(!roles.contains(&admin));
+        assert_eq!(roles.len(), 1);
+        assert!(!roles.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn roles_serde_works() {
+        let roles: Roles = ["support", "admin"]
+            .into_iter()
+            .map(|role| Role::new(role).unwrap())
+            .collect();
+        let json = serde_json::to_string(&roles).unwrap();
+        assert_eq!(json, r#"["admin","support"]"#);
+        assert_eq!(serde_json::from_str::<Roles>(&json).unwrap(), roles);
+        assert!(serde_json::from_str::<Roles>(r#"["1admin"]"#).is_err());
+    }
+}
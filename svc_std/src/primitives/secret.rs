@@ -0,0 +1,92 @@
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A container for user-entered secrets, like passwords before hashing.
+///
+/// The secret is wiped from memory on drop, redacted in debug output and
+/// intentionally doesn't implement `Display`. Use `SecretString::expose`
+/// to access the plaintext.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{Password, SecretString}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let secret = SecretString::from("mmholAhsbC123*");
+///     assert_eq!(format!("{secret:?}"), "SecretString(***)");
+///
+///     let password = Password::<Argon2PasswordHasher>::new(&secret)?;
+///     assert!(password.confirm(secret.expose()).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
+pub struct SecretString(String);
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretString(***)")
+    }
+}
+
+impl SecretString {
+    /// Initializes a new secret, taking ownership of the provided value.
+    pub fn new(value: String) -> Self {
+        Self(value)
+    }
+
+    /// Returns the plaintext secret.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl AsRef<str> for SecretString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        password_hasher::argon2::Argon2PasswordHasher,
+        primitives::{Error, Password},
+    };
+
+    #[test]
+    fn secret_debug_is_redacted() {
+        let secret = SecretString::new("mmholAhsbC123*".to_string());
+        assert_eq!(format!("{secret:?}"), "SecretString(***)");
+        assert_eq!(format!("{:?}", Some(&secret)), "Some(SecretString(***))");
+        assert_eq!(secret.expose(), "mmholAhsbC123*");
+    }
+
+    #[test]
+    fn secret_zeroize_works() {
+        let mut secret = SecretString::from("mmholAhsbC123*");
+        secret.zeroize();
+        assert_eq!(secret.expose(), "");
+    }
+
+    #[test]
+    fn secret_password_works() {
+        let secret = SecretString::from("mmholAhsbC123*");
+        let password = Password::<Argon2PasswordHasher>::new(&secret).unwrap();
+        assert!(password.confirm(secret.expose()).is_ok());
+        assert_eq!(
+            password.confirm(SecretString::from("blabla").expose()),
+            Err(Error::InvalidPassword)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use super::{DateTime, Error};
+use crate::traits::{password_hasher, PasswordHasher};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use data_encoding::BASE64URL_NOPAD;
+use std::marker::PhantomData;
+use zeroize::Zeroizing;
+
+/// Prefix of keys issued by `ApiKey::issue`, to make them recognizable, e.g. for secret scanners.
+pub const PREFIX: &str = "sk_";
+
+/// Number of random bytes in a key.
+pub const KEY_BYTES: usize = 32;
+
+/// An opaque API key or session token, of which only the hash is stored.
+///
+/// Keys are hashed with the provided `PasswordHasher`, so the hashing backend
+/// is shared with passwords. The key itself is returned once on issue.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{ApiKey, DateTime, Error}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let (api_key, key) = ApiKey::<Argon2PasswordHasher>::issue(None)?;
+///     assert!(key.starts_with("sk_"));
+///     // Store `api_key.hash()` and `api_key.expires_at()`, hand out `key`.
+///
+///     assert!(api_key.verify(&key, DateTime::now()).is_ok());
+///     assert_eq!(api_key.verify("sk_blabla", DateTime::now()), Err(Error::InvalidApiKey));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApiKey<T: PasswordHasher> {
+    hash: String,
+    expires_at: Option<DateTime>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: PasswordHasher> ApiKey<T> {
+    /// Issues a new key with the default prefix, that expires at the provided time, if any.
+    ///
+    /// Returns the API key and the key, which can't be recovered afterwards.
+    pub fn issue(expires_at: Option<DateTime>) -> Result<(Self, Zeroizing<String>), Error> {
+        Self::issue_with_prefix(PREFIX, expires_at)
+    }
+
+    /// Issues a new key with the provided prefix, that expires at the provided time, if any.
+    ///
+    /// Returns the API key and the key, which can't be recovered afterwards.
+    pub fn issue_with_prefix(
+        prefix: &str,
+        expires_at: Option<DateTime>,
+    ) -> Result<(Self, Zeroizing<String>), Error> {
+        let mut bytes = Zeroizing::new([0u8; KEY_BYTES]);
+        OsRng.fill_bytes(bytes.as_mut());
+        let key = Zeroizing::new(format!(
+            "{prefix}{}",
+            BASE64URL_NOPAD.encode(bytes.as_ref())
+        ));
+
+        let api_key = Self {
+            hash: T::hash(&key)?,
+            expires_at,
+            phantom: PhantomData,
+        };
+        Ok((api_key, key))
+    }
+
+    /// Initializes an API key from a stored hash and expiry time.
+    pub fn from_hash(hash: impl Into<String>, expires_at: Option<DateTime>) -> Self {
+        Self {
+            hash: hash.into(),
+            expires_at,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the hash of the key, which is safe to store.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Returns the time at which the key expires, if any.
+    pub fn expires_at(&self) -> Option<DateTime> {
+        self.expires_at
+    }
+
+    /// Confirms whether the provided key matches and hasn't expired at the provided time.
+    ///
+    /// Returns `Error::ExpiredApiKey` if the key has expired, or `Error::InvalidApiKey`
+    /// if the provided key doesn't match.
+    pub fn verify(&self, key: &str, now: DateTime) -> Result<(), Error> {
+        if self.expires_at.is_some_and(|expires_at| now >= expires_at) {
+            return Err(Error::ExpiredApiKey);
+        }
+
+        match T::confirm_password(key, &self.hash) {
+            Ok(()) => Ok(()),
+            Err(password_hasher::Error::InvalidPassword) => Err(Error::InvalidApiKey),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::password_hasher::argon2::Argon2PasswordHasher;
+
+    type Key = ApiKey<Argon2PasswordHasher>;
+
+    #[test]
+    fn issue_and_verify_works() {
+        let (api_key, key) = Key::issue(None).unwrap();
+        assert!(key.starts_with(PREFIX));
+        assert_eq!(key.len(), PREFIX.len() + 43);
+        assert!(api_key.hash().starts_with("$argon2id$"));
+        assert_eq!(api_key.expires_at(), None);
+
+        let stored = Key::from_hash(api_key.hash(), api_key.expires_at());
+        assert!(stored.verify(&key, DateTime::now()).is_ok());
+
+        let (_, key) = Key::issue_with_prefix("ses_", None).unwrap();
+        assert!(key.starts_with("ses_"));
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let (api_key, key) = Key::issue(None).unwrap();
+        let (_, other_key) = Key::issue(None).unwrap();
+        for wrong in ["", "sk_blabla", &other_key, &key[..key.len() - 1]] {
+            assert_eq!(
+                api_key.verify(wrong, DateTime::now()),
+                Err(Error::InvalidApiKey)
+            );
+        }
+    }
+
+    #[test]
+    fn expired_key_fails() {
+        let expires_at = DateTime::from_unix_secs(1_700_000_000);
+        let (api_key, key) = Key::issue(Some(expires_at)).unwrap();
+        assert!(api_key
+            .verify(&key, DateTime::from_unix_secs(1_699_999_999))
+            .is_ok());
+        assert_eq!(api_key.verify(&key, expires_at), Err(Error::ExpiredApiKey));
+        assert_eq!(
+            api_key.verify(&key, DateTime::now()),
+            Err(Error::ExpiredApiKey)
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use super::{error::ValidationError, Error};
+use crate::traits::{authenticatable, TotpAuthenticatable};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use std::time::{SystemTime, UNIX_EPOCH};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroizing;
+
+/// Number of seconds a code is valid for.
+pub const STEP: u64 = 30;
+
+/// Number of digits of a code.
+pub const DIGITS: usize = 6;
+
+/// Time-based one-time password generator and verifier as specified in RFC 6238.
+///
+/// Codes are computed with HMAC-SHA1 over 30 second steps. By default, codes
+/// from one step before or after the current one are accepted to allow for
+/// clock drift, use `Totp::with_skew` to change this.
+///
+/// ```rust
+/// # use crate::svc_std::primitives::Totp;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let totp = Totp::new("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ")?;
+///     assert_eq!(totp.generate_at(59), "287082");
+///     assert!(totp.verify_at("287082", 59).is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Eq, PartialEq)]
+pub struct Totp {
+    secret: Zeroizing<Vec<u8>>,
+    skew: u8,
+}
+
+impl std::fmt::Debug for Totp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Totp")
+            .field("secret", &"***")
+            .field("skew", &self.skew)
+            .finish()
+    }
+}
+
+impl Totp {
+    /// Initializes a new Totp with the provided base32 encoded secret.
+    ///
+    /// Padding and lowercase characters are accepted.
+    /// Returns a validation error if the secret isn't valid base32 or is empty.
+    pub fn new(secret: &str) -> Result<Self, Error> {
+        let normalized = Zeroizing::new(secret.trim_end_matches('=').to_ascii_uppercase());
+        let secret = BASE32_NOPAD
+            .decode(normalized.as_bytes())
+            .map_err(|_| ValidationError::TotpSecret)?;
+        if secret.is_empty() {
+            return Err(ValidationError::TotpSecret.into());
+        }
+
+        Ok(Self {
+            secret: Zeroizing::new(secret),
+            skew: 1,
+        })
+    }
+
+    /// Sets the number of steps before and after the current one for which codes are accepted.
+    pub fn with_skew(self, skew: u8) -> Self {
+        Self { skew, ..self }
+    }
+
+    /// Returns the base32 encoded secret, e.g. for provisioning authenticator apps.
+    pub fn secret(&self) -> String {
+        BASE32_NOPAD.encode(&self.secret)
+    }
+
+    /// Returns the code for the current time.
+    pub fn generate(&self) -> String {
+        self.generate_at(now())
+    }
+
+    /// Returns the code for the provided unix timestamp in seconds.
+    pub fn generate_at(&self, timestamp: u64) -> String {
+        self.code(timestamp / STEP)
+    }
+
+    /// Confirms whether the provided code is valid for the current time.
+    pub fn verify(&self, code: &str) -> Result<(), Error> {
+        self.verify_at(code, now())
+    }
+
+    /// Confirms whether the provided code is valid for the provided unix timestamp in seconds.
+    ///
+    /// Returns `Error::InvalidTotpCode` if the code doesn't match any step within the skew.
+    pub fn verify_at(&self, code: &str, timestamp: u64) -> Result<(), Error> {
+        if code.len() != DIGITS || !code.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::InvalidTotpCode);
+        }
+
+        let counter = timestamp / STEP;
+        let skew = u64::from(self.skew);
+        let mut valid = Choice::from(0);
+        for step in counter.saturating_sub(skew)..=counter.saturating_add(skew) {
+            // Check every step to keep the duration independent of the matching step.
+            valid |= self.code(step).as_bytes().ct_eq(code.as_bytes());
+        }
+
+        match bool::from(valid) {
+            true => Ok(()),
+            false => Err(Error::InvalidTotpCode),
+        }
+    }
+
+    fn code(&self, counter: u64) -> String {
+        let mut mac =
+            Hmac::<Sha1>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(&counter.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+
+        // Dynamic truncation as specified in RFC 4226.
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            digest[offset] & 0x7f,
+            digest[offset + 1],
+            digest[offset + 2],
+            digest[offset + 3],
+        ]);
+
+        format!(
+            "{:0width$}",
+            binary % 10u32.pow(DIGITS as u32),
+            width = DIGITS
+        )
+    }
+}
+
+impl TotpAuthenticatable<Error> for Totp {
+    fn confirm_totp(&self, code: &str) -> authenticatable::Result<Error> {
+        self.verify(code)
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Base32 encoding of the RFC 6238 SHA1 test secret "12345678901234567890".
+    const SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn rfc6238_vectors_work() {
+        let totp = Totp::new(SECRET).unwrap();
+
+        // The RFC lists 8 digit codes, 6 digit codes are their last 6 digits.
+        let vectors = [
+            (59, "287082"),
+            (1111111109, "081804"),
+            (1111111111, "050471"),
+            (1234567890, "005924"),
+            (2000000000, "279037"),
+            (20000000000, "353130"),
+        ];
+        for (timestamp, code) in vectors {
+            assert_eq!(totp.generate_at(timestamp), code);
+            assert!(totp.verify_at(code, timestamp).is_ok());
+        }
+    }
+
+    #[test]
+    fn skew_works() {
+        let totp = Totp::new(SECRET).unwrap();
+        let code = totp.generate_at(1111111111);
+
+        assert!(totp.verify_at(&code, 1111111111 + STEP).is_ok());
+        assert!(totp.verify_at(&code, 1111111111 - STEP).is_ok());
+        assert_eq!(
+            totp.verify_at(&code, 1111111111 + 2 * STEP),
+            Err(Error::InvalidTotpCode)
+        );
+
+        let strict = totp.with_skew(0);
+        assert!(strict.verify_at(&code, 1111111111).is_ok());
+        assert_eq!(
+            strict.verify_at(&code, 1111111111 + STEP),
+            Err(Error::InvalidTotpCode)
+        );
+    }
+
+    #[test]
+    fn invalid_input_fails() {
+        for secret in ["", "1!", "GEZDGNBV1"] {
+            assert_eq!(
+                Totp::new(secret),
+                Err(Error::Validation(ValidationError::TotpSecret))
+            );
+        }
+
+        let totp = Totp::new(&SECRET.to_lowercase()).unwrap();
+        assert_eq!(totp.secret(), SECRET);
+        for code in ["", "28708", "2870822", "28708a"] {
+            assert_eq!(totp.verify_at(code, 59), Err(Error::InvalidTotpCode));
+        }
+
+        // Ensure that the secret doesn't leak through debug output.
+        assert!(!format!("{totp:?}").contains(SECRET));
+    }
+
+    #[test]
+    fn totp_authenticatable_works() {
+        let totp = Totp::new(SECRET).unwrap();
+        assert!(totp.confirm_totp(&totp.generate()).is_ok());
+    }
+}
@@ -0,0 +1,154 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+use ::url::Url as CoreUrl;
+
+/// Schemes allowed by `Url::new`.
+pub const DEFAULT_SCHEMES: [&str; 2] = ["http", "https"];
+
+/// A validatable URL field.
+///
+/// Stores the parsed URL, so validation happens once on construction.
+/// Only `http` and `https` URLs are accepted by default, which rejects
+/// schemes like `javascript:` and `file:`. Use `Url::new_with_schemes`
+/// to allow other schemes.
+///
+/// ```rust
+/// # use crate::svc_std::{traits::Validatable, primitives::{Error, Url, ValidationError}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let url = Url::new("https://example.com/hooks")?;
+///     assert!(url.validate().is_ok());
+///     assert_eq!(url.host(), Some("example.com"));
+///     assert_eq!(Url::new("javascript:alert(1)"), Err(Error::Validation(ValidationError::Url)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Url(CoreUrl);
+
+impl Validatable<Error> for Url {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        // The URL and its scheme are validated on construction.
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for Url {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for Url {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Url::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Url {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Url {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Url::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for Url {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Url {
+    /// Initializes a new URL instance, allowing the default schemes.
+    ///
+    /// Returns a validation error if the provided value can't be parsed
+    /// or doesn't use one of the default schemes.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        Self::new_with_schemes(value, &DEFAULT_SCHEMES)
+    }
+
+    /// Initializes a new URL instance, allowing the provided lowercase schemes.
+    ///
+    /// Returns a validation error if the provided value can't be parsed
+    /// or doesn't use one of the provided schemes.
+    pub fn new_with_schemes(value: &str, schemes: &[&str]) -> Result<Self, Error> {
+        let url = CoreUrl::parse(value).map_err(|_| ValidationError::Url)?;
+        if !schemes.contains(&url.scheme()) {
+            return Err(ValidationError::Url.into());
+        }
+        Ok(Self(url))
+    }
+
+    /// Returns the lowercase scheme of the URL, e.g. `https`.
+    pub fn scheme(&self) -> &str {
+        self.0.scheme()
+    }
+
+    /// Returns the host of the URL, if any.
+    pub fn host(&self) -> Option<&str> {
+        self.0.host_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_validation_works() {
+        let url = Url::new("HTTPS://Example.com:8080/hooks?id=1").unwrap();
+        assert_eq!(url.scheme(), "https");
+        assert_eq!(url.host(), Some("example.com"));
+        assert_eq!(url.to_string(), "https://example.com:8080/hooks?id=1");
+        assert!(Url::new("http://localhost").is_ok());
+    }
+
+    #[test]
+    fn url_scheme_restriction_works() {
+        for value in [
+            "javascript:alert(1)",
+            "file:///etc/passwd",
+            "ftp://example.com",
+        ] {
+            assert_eq!(
+                Url::new(value),
+                Err(Error::Validation(ValidationError::Url)),
+                "{value}"
+            );
+        }
+
+        let url = Url::new_with_schemes("ftp://example.com", &["ftp"]).unwrap();
+        assert_eq!(url.scheme(), "ftp");
+        assert!(Url::new_with_schemes("https://example.com", &["ftp"]).is_err());
+    }
+
+    #[test]
+    fn malformed_url_fails() {
+        for value in ["", "example.com", "https://", "https://exa mple.com"] {
+            assert_eq!(
+                Url::new(value),
+                Err(Error::Validation(ValidationError::Url)),
+                "{value}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn url_serde_works() {
+        let url = Url::new("https://example.com/").unwrap();
+        let json = serde_json::to_string(&url).unwrap();
+        assert_eq!(json, r#""https://example.com/""#);
+        assert_eq!(serde_json::from_str::<Url>(&json).unwrap(), url);
+        assert!(serde_json::from_str::<Url>(r#""file:///etc/passwd""#).is_err());
+    }
+}
@@ -1,39 +1,330 @@
-use super::{Email, Error, Password};
-use crate::traits::{Authenticatable, PasswordHasher};
+use super::{
+    DateTime, Email, Error, Password, PasswordPolicy, Role, Roles, SecretString, ValidationError,
+};
+use crate::traits::{
+    validatable::FieldContext, Authenticatable, ConfiguredPasswordHasher, EventSourced,
+    Identifiable, PasswordHasher, SoftDeletable, Timestamped, Validatable,
+};
+use std::time::Duration;
 
 pub trait Config {
     type Id: Default + PartialEq;
+    /// Field identifying the user on sign in, e.g. `Email` or `Username`.
+    type Identity: Clone + Eq + Validatable<Error> + for<'a> TryFrom<&'a str, Error = Error>;
     type PasswordHasher: PasswordHasher;
     type DateTime: Clone + Copy + Default + Eq + PartialEq;
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Domain events recorded by the user's mutation methods.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UserEvent<T: Config> {
+    /// The identity was changed to the contained value with `update_identity`.
+    IdentityChanged(T::Identity),
+
+    /// The email of a user identified by email was changed to the contained value
+    /// with `update_email`.
+    EmailChanged(Email),
+
+    /// The password was changed, the new password is never included.
+    PasswordChanged,
+
+    /// The contained role was granted.
+    RoleGranted(Role),
+
+    /// The contained role was revoked.
+    RoleRevoked(Role),
+
+    /// The user was soft deleted.
+    Deleted,
+
+    /// The user was restored after a soft deletion.
+    Restored,
+}
+
 /// Entity for user data and logic.
+///
+/// With the `serde` feature users can be serialized, the password is always skipped.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct User<T: Config> {
     id: T::Id,
-    email: Email,
+    identity: T::Identity,
+    #[cfg_attr(feature = "serde", serde(skip))]
     password: Password<T::PasswordHasher>,
     created: T::DateTime,
     modified: T::DateTime,
+    password_changed_at: T::DateTime,
+    deleted: Option<T::DateTime>,
+    roles: Roles,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    password_policy: PasswordPolicy,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    events: Vec<UserEvent<T>>,
 }
 
 impl<T: Config> User<T> {
     /// Initializes a new user builder.
-    pub fn builder() -> UserBuilder<T, HasId<T>, NoEmail, NoPassword, HasCreated<T>, HasModified<T>>
-    {
-        let now = T::DateTime::default();
-
+    ///
+    /// The creation and modification times must be set explicitly,
+    /// either with the provided values or with the current time.
+    ///
+    /// ```rust
+    /// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{user::{Config, User}, DateTime, Email, Uuid}};
+    /// # struct App;
+    /// # impl Config for App {
+    /// #     type Id = Uuid;
+    /// #     type Identity = Email;
+    /// #     type PasswordHasher = Argon2PasswordHasher;
+    /// #     type DateTime = DateTime;
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let user = User::<App>::builder()
+    ///         .email("john.doe@example.com")?
+    ///         .password("mmholAhsbC123*")?
+    ///         .created_now()
+    ///         .modified_now()
+    ///         .build();
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Building without setting the times doesn't compile.
+    ///
+    /// ```rust,compile_fail
+    /// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{user::{Config, User}, DateTime, Email, Uuid}};
+    /// # struct App;
+    /// # impl Config for App {
+    /// #     type Id = Uuid;
+    /// #     type Identity = Email;
+    /// #     type PasswordHasher = Argon2PasswordHasher;
+    /// #     type DateTime = DateTime;
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let user = User::<App>::builder()
+    ///         .email("john.doe@example.com")?
+    ///         .password("mmholAhsbC123*")?
+    ///         .build();
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Neither does setting only one of them.
+    ///
+    /// ```rust,compile_fail
+    /// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{user::{Config, User}, DateTime, Email, Uuid}};
+    /// # struct App;
+    /// # impl Config for App {
+    /// #     type Id = Uuid;
+    /// #     type Identity = Email;
+    /// #     type PasswordHasher = Argon2PasswordHasher;
+    /// #     type DateTime = DateTime;
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let user = User::<App>::builder()
+    ///         .email("john.doe@example.com")?
+    ///         .password("mmholAhsbC123*")?
+    ///         .created_now()
+    ///         .build();
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{user::{Config, User}, DateTime, Email, Uuid}};
+    /// # struct App;
+    /// # impl Config for App {
+    /// #     type Id = Uuid;
+    /// #     type Identity = Email;
+    /// #     type PasswordHasher = Argon2PasswordHasher;
+    /// #     type DateTime = DateTime;
+    /// # }
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let user = User::<App>::builder()
+    ///         .email("john.doe@example.com")?
+    ///         .password("mmholAhsbC123*")?
+    ///         .modified_now()
+    ///         .build();
+    /// #    Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> UserBuilder<T, HasId<T>, NoIdentity, NoPassword, NoCreated, NoModified> {
         UserBuilder {
             id: HasId(T::Id::default()),
-            email: NoEmail,
+            identity: NoIdentity,
             password: NoPassword,
-            created: HasCreated(now),
-            modified: HasModified(now),
+            created: NoCreated,
+            modified: NoModified,
+            password_changed_at: None,
+            deleted: None,
+            roles: Roles::new(),
+            policy: PasswordPolicy::default(),
             phantom: std::marker::PhantomData,
         }
     }
+
+    /// Returns the user's id.
+    pub fn id(&self) -> &T::Id {
+        &self.id
+    }
+
+    /// Returns the user's identity.
+    pub fn identity(&self) -> &T::Identity {
+        &self.identity
+    }
+
+    /// Returns the user's creation time.
+    pub fn created(&self) -> T::DateTime {
+        self.created
+    }
+
+    /// Returns the user's last modification time.
+    pub fn modified(&self) -> T::DateTime {
+        self.modified
+    }
+
+    /// Returns the time the user's password was last changed.
+    pub fn password_changed_at(&self) -> T::DateTime {
+        self.password_changed_at
+    }
+
+    /// Returns the user's deletion time, if deleted.
+    pub fn deleted(&self) -> Option<T::DateTime> {
+        self.deleted
+    }
+
+    /// Returns the policy password updates are validated against.
+    pub fn password_policy(&self) -> &PasswordPolicy {
+        &self.password_policy
+    }
+
+    /// Returns the user's roles.
+    pub fn roles(&self) -> &Roles {
+        &self.roles
+    }
+
+    /// Confirms whether the user has the provided role.
+    ///
+    /// Invalid roles are never granted, so they always yield false.
+    pub fn has_role(&self, role: &str) -> bool {
+        Role::new(role).is_ok_and(|role| self.roles.contains(&role))
+    }
+
+    /// Grants the provided role and bumps the modification time if it wasn't granted yet.
+    ///
+    /// Returns a validation error is the provided input is invalid.
+    pub fn grant(&mut self, role: &str) -> Result<()> {
+        let role = Role::new(role)?;
+        if self.roles.insert(role.clone()) {
+            self.modified = T::DateTime::default();
+            self.events.push(UserEvent::RoleGranted(role));
+        }
+        Ok(())
+    }
+
+    /// Revokes the provided role and bumps the modification time if it was granted.
+    ///
+    /// Returns a validation error is the provided input is invalid.
+    pub fn revoke(&mut self, role: &str) -> Result<()> {
+        let role = Role::new(role)?;
+        if self.roles.remove(&role) {
+            self.modified = T::DateTime::default();
+            self.events.push(UserEvent::RoleRevoked(role));
+        }
+        Ok(())
+    }
+
+    /// Confirms whether the provided password matches using the provided hasher's
+    /// configuration, which is required for passwords hashed with a secret.
+    ///
+    /// Returns `Error::InvalidPassword` if the provided password is invalid.
+    pub fn confirm_password_with(&self, password: &str, hasher: &T::PasswordHasher) -> Result<()>
+    where
+        T::PasswordHasher: ConfiguredPasswordHasher,
+    {
+        self.password.confirm_with(password, hasher)
+    }
+
+    /// Updates the identity with the provided input and bumps the modification time.
+    ///
+    /// Returns a validation error is the provided input is invalid,
+    /// in which case the user is left unchanged.
+    pub fn update_identity(&mut self, identity: &str) -> Result<()> {
+        self.identity = T::Identity::try_from(identity)?;
+        self.modified = T::DateTime::default();
+        self.events
+            .push(UserEvent::IdentityChanged(self.identity.clone()));
+        Ok(())
+    }
+
+    /// Updates the password with the provided input and bumps both the modification
+    /// and password change time.
+    ///
+    /// The password is validated against the user's password policy and hashed with
+    /// the hasher's default configuration, use `update_password_with` for users built
+    /// with `password_with_hasher`.
+    ///
+    /// Returns a validation error is the provided input is invalid,
+    /// in which case the user is left unchanged.
+    pub fn update_password(&mut self, password: &str) -> Result<()> {
+        self.set_password(Password::new_with_policy(password, &self.password_policy)?);
+        Ok(())
+    }
+
+    /// Updates the password with the provided input, validated against the user's
+    /// password policy and hashed with the provided hasher's configuration, and bumps
+    /// both the modification and password change time.
+    ///
+    /// Returns a validation error is the provided input is invalid,
+    /// in which case the user is left unchanged.
+    pub fn update_password_with(&mut self, password: &str, hasher: &T::PasswordHasher) -> Result<()>
+    where
+        T::PasswordHasher: ConfiguredPasswordHasher,
+    {
+        self.set_password(Password::new_with_hasher(
+            password,
+            &self.password_policy,
+            hasher,
+        )?);
+        Ok(())
+    }
+
+    fn set_password(&mut self, password: Password<T::PasswordHasher>) {
+        self.password = password;
+        let now = T::DateTime::default();
+        self.modified = now;
+        self.password_changed_at = now;
+        self.events.push(UserEvent::PasswordChanged);
+    }
+}
+
+/// Shorthands for users identified by their email.
+impl<T: Config<Identity = Email>> User<T> {
+    /// Returns the user's email.
+    pub fn email(&self) -> &Email {
+        &self.identity
+    }
+
+    /// Updates the email with the provided input and bumps the modification time.
+    ///
+    /// Returns a validation error is the provided input is invalid,
+    /// in which case the user is left unchanged.
+    pub fn update_email(&mut self, email: &str) -> Result<()> {
+        self.identity = Email::new(email)?;
+        self.modified = T::DateTime::default();
+        self.events
+            .push(UserEvent::EmailChanged(self.identity.clone()));
+        Ok(())
+    }
+}
+
+/// Password age checks, e.g. to force a reset when rotation is required.
+impl<T: Config<DateTime = DateTime>> User<T> {
+    /// Confirms whether the password was changed longer ago than the provided age.
+    pub fn password_expired(&self, max_age: Duration) -> bool {
+        self.password_changed_at.elapsed() > max_age
+    }
 }
 
 impl<T: Config> Authenticatable<Error> for User<T> {
@@ -42,6 +333,112 @@ impl<T: Config> Authenticatable<Error> for User<T> {
     }
 }
 
+/// Validates the id, identity and roles, reporting the path of the failed field.
+///
+/// The password is validated before hashing and therefore isn't revalidated.
+impl<T: Config> Validatable<Error> for User<T>
+where
+    T::Id: Validatable<Error>,
+{
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        self.id.validate().map_err(|e| e.at("id"))?;
+        self.identity.validate().map_err(|e| e.at("identity"))?;
+        self.roles.validate().map_err(|e| e.at("roles"))
+    }
+}
+
+impl<T: Config> Identifiable for User<T> {
+    type Id = T::Id;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+}
+
+/// Marks the user as deleted, both deleting and restoring bump the modification time.
+///
+/// Deleting an already deleted user keeps the original deletion time.
+impl<T: Config<DateTime = DateTime>> SoftDeletable for User<T> {
+    fn deleted_at(&self) -> Option<DateTime> {
+        self.deleted
+    }
+
+    fn soft_delete(&mut self) {
+        if self.deleted.is_none() {
+            let now = DateTime::now();
+            self.deleted = Some(now);
+            self.modified = now;
+            self.events.push(UserEvent::Deleted);
+        }
+    }
+
+    fn restore(&mut self) {
+        if self.deleted.take().is_some() {
+            self.modified = DateTime::now();
+            self.events.push(UserEvent::Restored);
+        }
+    }
+}
+
+/// Records events from the mutation methods, creating or loading a user records none.
+impl<T: Config> EventSourced for User<T> {
+    type Event = UserEvent<T>;
+
+    fn drain_events(&mut self) -> Vec<UserEvent<T>> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+impl<T: Config<DateTime = DateTime>> Timestamped for User<T> {
+    fn created(&self) -> DateTime {
+        self.created
+    }
+
+    fn modified(&self) -> DateTime {
+        self.modified
+    }
+}
+
+/// Unvalidated input for creating a user, e.g. a row of an import.
+#[derive(Clone, Debug)]
+pub struct UserInput {
+    pub identity: String,
+    pub password: SecretString,
+}
+
+/// Builds a user for every provided input, without aborting on the first error.
+///
+/// Returns a result per input in the same order, failures are paired with
+/// the index of their input.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{user::{validate_batch, Config, UserInput}, DateTime, Email, Uuid}};
+/// # struct App;
+/// # impl Config for App {
+/// #     type Id = Uuid;
+/// #     type Identity = Email;
+/// #     type PasswordHasher = Argon2PasswordHasher;
+/// #     type DateTime = DateTime;
+/// # }
+/// let rows = [UserInput { identity: "john.doe".to_string(), password: "mmholAhsbC123*".into() }];
+/// let results = validate_batch::<App>(rows);
+/// assert!(matches!(results[0], Err((0, _))));
+/// ```
+pub fn validate_batch<T: Config>(
+    rows: impl IntoIterator<Item = UserInput>,
+) -> Vec<core::result::Result<User<T>, (usize, Error)>> {
+    rows.into_iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let user = User::builder()
+                .identity(&row.identity)
+                .and_then(|builder| builder.password(row.password.expose()))
+                .map_err(|e| (i, e))?;
+            Ok(user.created_now().modified_now().build())
+        })
+        .collect()
+}
+
 /// Type states for the user builder.
 ///
 /// Builder state indicating that no id has been set.
@@ -52,13 +449,13 @@ pub struct NoId;
 #[derive(Debug, PartialEq)]
 pub struct HasId<T: Config>(T::Id);
 
-/// Builder state indicating that no email has been set.
+/// Builder state indicating that no identity has been set.
 #[derive(Debug, PartialEq)]
-pub struct NoEmail;
+pub struct NoIdentity;
 
-/// Builder state indicating that an enail has been set.
+/// Builder state indicating that an identity has been set.
 #[derive(Debug, PartialEq)]
-pub struct HasEmail(Email);
+pub struct HasIdentity<T: Config>(T::Identity);
 
 /// Builder state indicating that no password has been set.
 #[derive(Debug, PartialEq)]
@@ -88,10 +485,14 @@ pub struct HasModified<T: Config>(T::DateTime);
 #[derive(Debug, PartialEq)]
 pub struct UserBuilder<T: Config, I, E, P, C, M> {
     id: I,
-    email: E,
+    identity: E,
     password: P,
     created: C,
     modified: M,
+    password_changed_at: Option<T::DateTime>,
+    deleted: Option<T::DateTime>,
+    roles: Roles,
+    policy: PasswordPolicy,
     phantom: std::marker::PhantomData<T>,
 }
 
@@ -102,68 +503,180 @@ impl<T: Config, I, E, P, C, M> UserBuilder<T, I, E, P, C, M> {
     /// Returns a validation error is the provided input is invalid.
     pub fn id(self, id: T::Id) -> UserBuilder<T, HasId<T>, E, P, C, M> {
         let Self {
-            email,
+            identity,
             password,
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
             ..
         } = self;
         UserBuilder {
             id: HasId(id),
-            email,
+            identity,
             password,
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
         }
     }
 
-    /// Sets the email with the provided input.
+    /// Sets the id by parsing the provided input.
+    ///
+    /// Returns a validation error is the provided input is invalid.
+    pub fn id_from_str<'a>(self, id: &'a str) -> Result<UserBuilder<T, HasId<T>, E, P, C, M>>
+    where
+        T::Id: TryFrom<&'a str>,
+        Error: From<<T::Id as TryFrom<&'a str>>::Error>,
+    {
+        Ok(self.id(T::Id::try_from(id)?))
+    }
+
+    /// Sets the identity with the provided input.
     ///
     /// Returns a validation error is the provided input is invalid.
-    pub fn email(self, email: &'static str) -> Result<UserBuilder<T, I, HasEmail, P, C, M>> {
+    pub fn identity(self, identity: &str) -> Result<UserBuilder<T, I, HasIdentity<T>, P, C, M>> {
         let Self {
             id,
             password,
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
             ..
         } = self;
         Ok(UserBuilder {
             id,
-            email: HasEmail(Email::new(email)?),
+            identity: HasIdentity(T::Identity::try_from(identity)?),
             password,
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
         })
     }
 
-    /// Sets the password with the provided input.
+    /// Sets the policy the password is validated against, defaults to `PasswordPolicy::default()`.
+    /// The user keeps the policy to validate password updates.
+    ///
+    /// Must be set before the password, as the password is validated when it's set.
+    pub fn password_policy(self, policy: PasswordPolicy) -> Self {
+        Self { policy, ..self }
+    }
+
+    /// Sets the password with the provided input, validated against the password policy.
     ///
     /// Returns a validation error is the provided input is invalid.
-    pub fn password(
+    pub fn password(self, password: &str) -> Result<UserBuilder<T, I, E, HasPassword<T>, C, M>> {
+        let Self {
+            id,
+            identity,
+            created,
+            modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
+            phantom,
+            ..
+        } = self;
+        let password = Password::new_with_policy(password, &policy)?;
+
+        Ok(UserBuilder {
+            id,
+            identity,
+            password: HasPassword(password),
+            created,
+            modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
+            phantom,
+        })
+    }
+
+    /// Sets the password with the provided hash, e.g. when loading a stored user.
+    ///
+    /// The hash is used as is, without validation or rehashing.
+    pub fn password_hash(self, hash: &str) -> UserBuilder<T, I, E, HasPassword<T>, C, M> {
+        let Self {
+            id,
+            identity,
+            created,
+            modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
+            phantom,
+            ..
+        } = self;
+
+        UserBuilder {
+            id,
+            identity,
+            password: HasPassword(Password::from_hash(hash.to_string())),
+            created,
+            modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
+            phantom,
+        }
+    }
+
+    /// Sets the password with the provided input, validated against the password
+    /// policy and hashed with the provided hasher's configuration, e.g. elevated
+    /// cost parameters or a secret.
+    ///
+    /// Returns a validation error is the provided input is invalid.
+    pub fn password_with_hasher(
         self,
-        password: &'static str,
-    ) -> Result<UserBuilder<T, I, E, HasPassword<T>, C, M>> {
+        password: &str,
+        hasher: &T::PasswordHasher,
+    ) -> Result<UserBuilder<T, I, E, HasPassword<T>, C, M>>
+    where
+        T::PasswordHasher: ConfiguredPasswordHasher,
+    {
         let Self {
             id,
-            email,
+            identity,
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
             ..
         } = self;
-        let password = Password::new(password)?;
+        let password = Password::new_with_hasher(password, &policy, hasher)?;
 
         Ok(UserBuilder {
             id,
-            email,
+            identity,
             password: HasPassword(password),
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
         })
     }
@@ -172,64 +685,323 @@ impl<T: Config, I, E, P, C, M> UserBuilder<T, I, E, P, C, M> {
     pub fn created(self, created: T::DateTime) -> UserBuilder<T, I, E, P, HasCreated<T>, M> {
         let Self {
             id,
-            email,
+            identity,
             password,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
             ..
         } = self;
 
         UserBuilder {
             id,
-            email,
+            identity,
             password,
             created: HasCreated(created),
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
         }
     }
 
-    /// Sets the creation time with the provided input.
+    /// Sets the time the password was last changed, e.g. when loading a stored user.
+    ///
+    /// Defaults to the creation time.
+    pub fn password_changed_at(self, password_changed_at: T::DateTime) -> Self {
+        Self {
+            password_changed_at: Some(password_changed_at),
+            ..self
+        }
+    }
+
+    /// Sets the deletion time of a soft deleted user, e.g. when loading a stored user.
+    pub fn deleted(self, deleted: Option<T::DateTime>) -> Self {
+        Self { deleted, ..self }
+    }
+
+    /// Sets the roles.
+    pub fn roles(self, roles: Roles) -> Self {
+        Self { roles, ..self }
+    }
+
+    /// Sets the creation time to the current time.
+    pub fn created_now(self) -> UserBuilder<T, I, E, P, HasCreated<T>, M> {
+        self.created(T::DateTime::default())
+    }
+
+    /// Sets the modification time with the provided input.
     pub fn modified(self, modified: T::DateTime) -> UserBuilder<T, I, E, P, C, HasModified<T>> {
         let Self {
             id,
-            email,
+            identity,
             password,
             created,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
             ..
         } = self;
 
         UserBuilder {
             id,
-            email,
+            identity,
             password,
             created,
             modified: HasModified(modified),
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             phantom,
         }
     }
+
+    /// Sets the modification time to the current time.
+    pub fn modified_now(self) -> UserBuilder<T, I, E, P, C, HasModified<T>> {
+        self.modified(T::DateTime::default())
+    }
 }
 
-impl<T: Config> UserBuilder<T, HasId<T>, HasEmail, HasPassword<T>, HasCreated<T>, HasModified<T>> {
+/// Shorthand for users identified by their email.
+impl<T: Config<Identity = Email>, I, E, P, C, M> UserBuilder<T, I, E, P, C, M> {
+    /// Sets the email with the provided input.
+    ///
+    /// Returns a validation error is the provided input is invalid.
+    pub fn email(self, email: &str) -> Result<UserBuilder<T, I, HasIdentity<T>, P, C, M>> {
+        self.identity(email)
+    }
+}
+
+impl<T: Config>
+    UserBuilder<T, HasId<T>, HasIdentity<T>, HasPassword<T>, HasCreated<T>, HasModified<T>>
+{
     /// Builds the a user instance.
     ///
     /// Can only be used when all states have been set.
     pub fn build(self) -> User<T> {
         let Self {
             id,
-            email,
+            identity,
             password,
             created,
             modified,
+            password_changed_at,
+            deleted,
+            roles,
+            policy,
             ..
         } = self;
         User {
             id: id.0,
-            email: email.0,
+            identity: identity.0,
             password: password.0,
             created: created.0,
             modified: modified.0,
+            password_changed_at: password_changed_at.unwrap_or(created.0),
+            deleted,
+            roles,
+            password_policy: policy,
+            events: Vec::new(),
+        }
+    }
+}
+
+/// Builder for User objects from dynamic input, e.g. forms.
+///
+/// Unlike `UserBuilder` completeness is checked at runtime, so the builder can be
+/// passed across function boundaries. Every missing or invalid field is reported
+/// at once, with the field's path.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, primitives::{user::{Config, DynUserBuilder}, DateTime, Email, Error, Uuid}};
+/// # struct App;
+/// # impl Config for App {
+/// #     type Id = Uuid;
+/// #     type Identity = Email;
+/// #     type PasswordHasher = Argon2PasswordHasher;
+/// #     type DateTime = DateTime;
+/// # }
+/// let errors = DynUserBuilder::<App>::new()
+///     .identity("john.doe")
+///     .created_now()
+///     .build()
+///     .err()
+///     .unwrap();
+/// let paths: Vec<_> = errors
+///     .iter()
+///     .filter_map(|e| match e {
+///         Error::Validation(e) => e.path(),
+///         _ => None,
+///     })
+///     .collect();
+/// assert_eq!(paths, ["identity", "password", "modified"]);
+/// ```
+pub struct DynUserBuilder<T: Config> {
+    id: Option<T::Id>,
+    identity: Option<String>,
+    password: Option<SecretString>,
+    created: Option<T::DateTime>,
+    modified: Option<T::DateTime>,
+    password_changed_at: Option<T::DateTime>,
+    deleted: Option<T::DateTime>,
+    roles: Roles,
+    policy: PasswordPolicy,
+}
+
+impl<T: Config> Default for DynUserBuilder<T> {
+    fn default() -> Self {
+        Self {
+            id: None,
+            identity: None,
+            password: None,
+            created: None,
+            modified: None,
+            password_changed_at: None,
+            deleted: None,
+            roles: Roles::new(),
+            policy: PasswordPolicy::default(),
+        }
+    }
+}
+
+/// Returns the value of a required field, or records why it's missing or invalid.
+fn require<V>(errors: &mut Vec<Error>, value: Option<Result<V>>, field: &str) -> Option<V> {
+    match value {
+        Some(Ok(value)) => Some(value),
+        Some(Err(e)) => {
+            errors.push(e.at(field));
+            None
+        }
+        None => {
+            errors.push(Error::from(ValidationError::Missing).at(field));
+            None
+        }
+    }
+}
+
+impl<T: Config> DynUserBuilder<T> {
+    /// Initializes a new, empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the id, a new id is generated if none is set.
+    pub fn id(self, id: T::Id) -> Self {
+        Self {
+            id: Some(id),
+            ..self
+        }
+    }
+
+    /// Sets the identity, which is validated on build.
+    pub fn identity(self, identity: &str) -> Self {
+        Self {
+            identity: Some(identity.to_string()),
+            ..self
+        }
+    }
+
+    /// Sets the password, which is validated and hashed on build.
+    pub fn password(self, password: impl Into<SecretString>) -> Self {
+        Self {
+            password: Some(password.into()),
+            ..self
+        }
+    }
+
+    /// Sets the policy the password is validated against, defaults to `PasswordPolicy::default()`.
+    /// The user keeps the policy to validate password updates.
+    pub fn password_policy(self, policy: PasswordPolicy) -> Self {
+        Self { policy, ..self }
+    }
+
+    /// Sets the creation time.
+    pub fn created(self, created: T::DateTime) -> Self {
+        Self {
+            created: Some(created),
+            ..self
+        }
+    }
+
+    /// Sets the creation time to the current time.
+    pub fn created_now(self) -> Self {
+        self.created(T::DateTime::default())
+    }
+
+    /// Sets the modification time.
+    pub fn modified(self, modified: T::DateTime) -> Self {
+        Self {
+            modified: Some(modified),
+            ..self
+        }
+    }
+
+    /// Sets the modification time to the current time.
+    pub fn modified_now(self) -> Self {
+        self.modified(T::DateTime::default())
+    }
+
+    /// Sets the time the password was last changed, defaults to the creation time.
+    pub fn password_changed_at(self, password_changed_at: T::DateTime) -> Self {
+        Self {
+            password_changed_at: Some(password_changed_at),
+            ..self
+        }
+    }
+
+    /// Sets the deletion time of a soft deleted user.
+    pub fn deleted(self, deleted: Option<T::DateTime>) -> Self {
+        Self { deleted, ..self }
+    }
+
+    /// Sets the roles.
+    pub fn roles(self, roles: Roles) -> Self {
+        Self { roles, ..self }
+    }
+
+    /// Builds the user instance.
+    ///
+    /// Returns an error for every missing or invalid field, missing fields are
+    /// reported as `ValidationError::Missing`.
+    pub fn build(self) -> core::result::Result<User<T>, Vec<Error>> {
+        let mut errors = Vec::new();
+        let identity = require(
+            &mut errors,
+            self.identity.as_deref().map(T::Identity::try_from),
+            "identity",
+        );
+        let password = require(
+            &mut errors,
+            self.password
+                .as_ref()
+                .map(|password| Password::new_with_policy(password.expose(), &self.policy)),
+            "password",
+        );
+        let created = require(&mut errors, self.created.map(Ok), "created");
+        let modified = require(&mut errors, self.modified.map(Ok), "modified");
+
+        match (identity, password, created, modified) {
+            (Some(identity), Some(password), Some(created), Some(modified)) => Ok(User {
+                id: self.id.unwrap_or_default(),
+                identity,
+                password,
+                created,
+                modified,
+                password_changed_at: self.password_changed_at.unwrap_or(created),
+                deleted: self.deleted,
+                roles: self.roles,
+                password_policy: self.policy,
+                events: Vec::new(),
+            }),
+            _ => Err(errors),
         }
     }
 }
@@ -239,12 +1011,18 @@ mod tests {
     use super::*;
     use crate::{
         password_hasher::argon2::Argon2PasswordHasher,
-        primitives::{DateTime, Uuid},
+        primitives::{
+            role::RolePermissions, DateTime, Email, Id, PasswordValidationError, Username, Uuid,
+            ValidationError,
+        },
+        traits::{Authorizable, EventSourced},
     };
+    use std::sync::OnceLock;
 
     struct App;
     impl Config for App {
         type Id = Uuid;
+        type Identity = Email;
         type PasswordHasher = Argon2PasswordHasher;
         type DateTime = DateTime;
     }
@@ -256,7 +1034,658 @@ mod tests {
             .unwrap()
             .password("mmholAhsbC123*")
             .unwrap()
+            .created_now()
+            .modified_now()
             .build();
         assert!(user.confirm_password("mmholAhsbC123*").is_ok());
     }
+
+    #[test]
+    fn user_id_from_str_works() {
+        let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let user = User::<App>::builder()
+            .id_from_str(id)
+            .unwrap()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert_eq!(user.id().to_string(), id);
+
+        assert!(User::<App>::builder().id_from_str("blabla").is_err());
+    }
+
+    #[test]
+    fn user_builder_with_hasher_works() {
+        let hasher = Argon2PasswordHasher::with_params(32768, 3, 1).unwrap();
+        let user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password_with_hasher("mmholAhsbC123*", &hasher)
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert!(user.password.to_string().contains("m=32768,t=3,p=1"));
+        assert!(user.confirm_password("mmholAhsbC123*").is_ok());
+        assert!(user.confirm_password("blabla").is_err());
+
+        // Ensure that passwords hashed with a secret confirm with the same hasher only.
+        let hasher = Argon2PasswordHasher::default().with_secret(b"pepper");
+        let user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password_with_hasher("mmholAhsbC123*", &hasher)
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert!(user
+            .confirm_password_with("mmholAhsbC123*", &hasher)
+            .is_ok());
+        assert_eq!(
+            user.confirm_password("mmholAhsbC123*"),
+            Err(Error::InvalidPassword)
+        );
+    }
+
+    #[test]
+    fn user_builder_password_policy_works() {
+        let policy = PasswordPolicy {
+            min_len: 16,
+            ..Default::default()
+        };
+        let builder = || User::<App>::builder().password_policy(policy.clone());
+        assert_eq!(
+            builder().password("mmholAhsbC123*").err(),
+            Some(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
+        );
+        assert!(builder()
+            .password_with_hasher("mmholAhsbC123*", &Argon2PasswordHasher::default())
+            .is_err());
+
+        // Ensure that the policy also applies to password updates.
+        let mut user = builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*abcd")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert_eq!(user.password_policy(), &policy);
+        assert_eq!(
+            user.update_password("nnholAhsbC123*"),
+            Err(Error::Validation(ValidationError::Password(
+                PasswordValidationError::TooShort
+            )))
+        );
+        assert!(user
+            .update_password_with("nnholAhsbC123*", &Argon2PasswordHasher::default())
+            .is_err());
+        assert!(user.confirm_password("mmholAhsbC123*abcd").is_ok());
+        assert!(user.update_password("nnholAhsbC123*abcd").is_ok());
+
+        let errors = DynUserBuilder::<App>::new()
+            .identity("john.doe@example.com")
+            .password("mmholAhsbC123*")
+            .password_policy(policy.clone())
+            .created_now()
+            .modified_now()
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn user_update_password_with_hasher_works() {
+        let hasher = Argon2PasswordHasher::default().with_secret(b"pepper");
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password_with_hasher("mmholAhsbC123*", &hasher)
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+
+        user.update_password_with("nnholAhsbC123*", &hasher)
+            .unwrap();
+        assert!(user
+            .confirm_password_with("nnholAhsbC123*", &hasher)
+            .is_ok());
+        assert_eq!(
+            user.confirm_password_with("mmholAhsbC123*", &hasher),
+            Err(Error::InvalidPassword)
+        );
+        assert_eq!(
+            user.confirm_password("nnholAhsbC123*"),
+            Err(Error::InvalidPassword)
+        );
+        assert!(user.update_password_with("blabla", &hasher).is_err());
+    }
+
+    #[test]
+    fn validate_batch_works() {
+        let row = |identity: &str, password: &str| UserInput {
+            identity: identity.to_string(),
+            password: password.into(),
+        };
+        let rows = vec![
+            row("john.doe@example.com", "mmholAhsbC123*"),
+            row("john.doe", "mmholAhsbC123*"),
+            row("jane.doe@example.com", "blabla"),
+            row("jane.doe@example.com", "hsbCmmolA321*"),
+        ];
+
+        let results = validate_batch::<App>(rows);
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().unwrap().email().as_str(),
+            "john.doe@example.com"
+        );
+        assert_eq!(
+            results[1].as_ref().err(),
+            Some(&(1, Error::Validation(ValidationError::Email)))
+        );
+        assert!(matches!(
+            results[2].as_ref().err(),
+            Some((2, Error::Validation(ValidationError::Password(_))))
+        ));
+        assert!(results[3]
+            .as_ref()
+            .unwrap()
+            .confirm_password("hsbCmmolA321*")
+            .is_ok());
+    }
+
+    #[test]
+    fn user_getters_work() {
+        let id = Uuid::new();
+        let created = DateTime::from_unix_secs(1_700_000_000);
+        let modified = DateTime::from_unix_secs(1_700_000_060);
+        let user = User::<App>::builder()
+            .id(id)
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created(created)
+            .modified(modified)
+            .build();
+        assert_eq!(user.id(), &id);
+        assert_eq!(user.email().to_string(), "john.doe@example.com");
+        assert_eq!(user.created(), created);
+        assert_eq!(user.modified(), modified);
+    }
+
+    #[test]
+    fn user_update_works() {
+        let modified = DateTime::from_unix_secs(1_700_000_000);
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified(modified)
+            .build();
+
+        user.update_email("jane.doe@example.com").unwrap();
+        assert_eq!(user.email().to_string(), "jane.doe@example.com");
+        assert!(user.modified() > modified);
+
+        let modified = user.modified();
+        user.update_password("hsbCmmolA321*").unwrap();
+        assert!(user.modified() >= modified);
+        assert!(user.confirm_password("hsbCmmolA321*").is_ok());
+        assert!(user.confirm_password("mmholAhsbC123*").is_err());
+
+        // Ensure that invalid input leaves the user unchanged.
+        let modified = user.modified();
+        assert!(user.update_email("jane.doe").is_err());
+        assert!(user.update_password("blabla").is_err());
+        assert_eq!(user.email().to_string(), "jane.doe@example.com");
+        assert!(user.confirm_password("hsbCmmolA321*").is_ok());
+        assert_eq!(user.modified(), modified);
+    }
+
+    #[test]
+    fn user_validation_reports_field() {
+        #[derive(Default, PartialEq)]
+        struct InvalidId;
+        impl Validatable<Error> for InvalidId {
+            fn validate(&self) -> crate::traits::validatable::Result<Error> {
+                Err(ValidationError::Id.into())
+            }
+        }
+
+        struct InvalidApp;
+        impl Config for InvalidApp {
+            type Id = InvalidId;
+            type Identity = Email;
+            type PasswordHasher = Argon2PasswordHasher;
+            type DateTime = DateTime;
+        }
+
+        let user = User::<InvalidApp>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        let Err(Error::Validation(error)) = user.validate() else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(error.path(), Some("id"));
+        assert_eq!(error.kind(), &ValidationError::Id);
+        assert_eq!(
+            error,
+            ValidationError::Field {
+                path: "id".to_string(),
+                kind: Box::new(ValidationError::Id)
+            }
+        );
+    }
+
+    #[test]
+    fn user_identifiable_works() {
+        fn ids<T: Identifiable>(items: &[T]) -> Vec<&T::Id> {
+            items.iter().map(Identifiable::id).collect()
+        }
+
+        let id = Uuid::new();
+        let user = User::<App>::builder()
+            .id(id)
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert_eq!(ids(&[user]), vec![&id]);
+    }
+
+    #[test]
+    fn user_timestamped_works() {
+        let created = DateTime::from_unix_secs(1_700_000_000);
+        let user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created(created)
+            .modified_now()
+            .build();
+        let entity: &dyn Timestamped = &user;
+        assert_eq!(entity.created(), created);
+        assert!(entity.modified() > created);
+    }
+
+    #[test]
+    fn user_with_typed_id_works() {
+        struct TypedApp;
+        impl Config for TypedApp {
+            type Id = Id<User<TypedApp>>;
+            type Identity = Email;
+            type PasswordHasher = Argon2PasswordHasher;
+            type DateTime = DateTime;
+        }
+
+        let id = Id::<User<TypedApp>>::new();
+        let user = User::<TypedApp>::builder()
+            .id(id)
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert_eq!(user.id, id);
+    }
+
+    #[test]
+    fn user_builder_accepts_runtime_strings() {
+        let email = format!("{}@{}", "john.doe", "example.com");
+        let password = ["mmholAhsbC", "123*"].concat();
+        let user = User::<App>::builder()
+            .email(&email)
+            .unwrap()
+            .password(&password)
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert_eq!(user.identity, Email::new(&email).unwrap());
+        assert!(user.confirm_password(&password).is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn user_serialize_works() {
+        let user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        let json = serde_json::to_value(&user).unwrap();
+        assert_eq!(json["id"], user.id.to_string());
+        assert_eq!(json["identity"], "john.doe@example.com");
+        assert!(json.get("password").is_none());
+        assert!(!json.to_string().contains("$argon2"));
+    }
+
+    #[test]
+    fn user_with_username_identity_works() {
+        struct UsernameApp;
+        impl Config for UsernameApp {
+            type Id = Uuid;
+            type Identity = Username;
+            type PasswordHasher = Argon2PasswordHasher;
+            type DateTime = DateTime;
+        }
+
+        let mut user = User::<UsernameApp>::builder()
+            .identity("John_Doe")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert_eq!(user.identity(), &Username::new("John_Doe").unwrap());
+        assert!(user.validate().is_ok());
+
+        user.update_identity("Jane_Doe").unwrap();
+        assert_eq!(user.identity().to_string(), "Jane_Doe");
+
+        // Ensure that the identity is validated by the configured type.
+        assert_eq!(
+            User::<UsernameApp>::builder()
+                .identity("john.doe@example.com")
+                .err(),
+            Some(Error::Validation(ValidationError::Username))
+        );
+        assert!(user.update_identity("-jane").is_err());
+        assert_eq!(user.identity().to_string(), "Jane_Doe");
+    }
+
+    #[test]
+    fn user_from_stored_fields_works() {
+        let stored = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created(DateTime::from_unix_secs(1_700_000_000))
+            .modified(DateTime::from_unix_secs(1_700_000_060))
+            .build();
+
+        let user = User::<App>::builder()
+            .id_from_str(&stored.id().to_string())
+            .unwrap()
+            .email(stored.email().as_str())
+            .unwrap()
+            .password_hash(stored.password.hash())
+            .created(stored.created())
+            .modified(stored.modified())
+            .build();
+        assert_eq!(user.id(), stored.id());
+        assert_eq!(user.email(), stored.email());
+        assert_eq!(user.password, stored.password);
+        assert_eq!(user.created(), stored.created());
+        assert_eq!(user.modified(), stored.modified());
+        assert!(user.confirm_password("mmholAhsbC123*").is_ok());
+        assert_eq!(user.confirm_password("blabla"), Err(Error::InvalidPassword));
+    }
+
+    #[test]
+    fn user_password_expiry_works() {
+        let max_age = Duration::from_secs(90 * 24 * 60 * 60);
+        let created = DateTime::now().checked_sub(max_age * 2).unwrap();
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created(created)
+            .modified(created)
+            .build();
+        assert_eq!(user.password_changed_at(), created);
+        assert!(user.password_expired(max_age));
+
+        user.update_password("nnholAhsbC123*").unwrap();
+        assert!(user.password_changed_at() > created);
+        assert_eq!(user.password_changed_at(), user.modified());
+        assert!(!user.password_expired(max_age));
+
+        // Ensure that the change time is restored when loading a stored user.
+        let user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password_hash(user.password.hash())
+            .created(created)
+            .modified(created)
+            .password_changed_at(created)
+            .build();
+        assert!(user.password_expired(max_age));
+    }
+
+    #[test]
+    fn user_events_work() {
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert!(user.drain_events().is_empty());
+
+        user.update_email("jane.doe@example.com").unwrap();
+        let events = user.drain_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            UserEvent::EmailChanged(email) if email.as_str() == "jane.doe@example.com"
+        ));
+        assert!(user.drain_events().is_empty());
+
+        user.update_identity("john.doe@example.com").unwrap();
+        assert!(matches!(
+            user.drain_events().as_slice(),
+            [UserEvent::IdentityChanged(email)] if email.as_str() == "john.doe@example.com"
+        ));
+
+        // Ensure that failed and no-op mutations don't record events.
+        assert!(user.update_email("jane.doe").is_err());
+        user.grant("admin").unwrap();
+        user.grant("admin").unwrap();
+        user.update_password("nnholAhsbC123*").unwrap();
+        user.soft_delete();
+        user.soft_delete();
+        user.restore();
+        user.revoke("admin").unwrap();
+        let events = user.drain_events();
+        assert!(matches!(
+            events.as_slice(),
+            [
+                UserEvent::RoleGranted(granted),
+                UserEvent::PasswordChanged,
+                UserEvent::Deleted,
+                UserEvent::Restored,
+                UserEvent::RoleRevoked(revoked),
+            ] if granted.as_str() == "admin" && revoked.as_str() == "admin"
+        ));
+    }
+
+    #[test]
+    fn user_soft_delete_works() {
+        let modified = DateTime::from_unix_secs(1_700_000_000);
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created(modified)
+            .modified(modified)
+            .build();
+        assert_eq!(user.deleted_at(), None);
+        assert!(!user.is_deleted());
+
+        user.soft_delete();
+        let deleted = user.deleted_at().unwrap();
+        assert!(user.is_deleted());
+        assert!(deleted > modified);
+        assert_eq!(user.modified(), deleted);
+
+        // Ensure that deleting again keeps the original deletion time.
+        user.soft_delete();
+        assert_eq!(user.deleted(), Some(deleted));
+
+        user.restore();
+        assert_eq!(user.deleted_at(), None);
+        assert!(user.modified() >= deleted);
+
+        // Ensure that the deletion time is restored when loading a stored user.
+        let user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password_hash(user.password.hash())
+            .created(modified)
+            .modified(deleted)
+            .deleted(Some(deleted))
+            .build();
+        assert_eq!(user.deleted_at(), Some(deleted));
+    }
+
+    #[test]
+    fn user_roles_work() {
+        let modified = DateTime::from_unix_secs(1_700_000_000);
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .roles([Role::new("support").unwrap()].into_iter().collect())
+            .created(modified)
+            .modified(modified)
+            .build();
+        assert!(user.has_role("support"));
+        assert!(!user.has_role("admin"));
+        assert!(!user.has_role("not a role"));
+
+        user.grant("admin").unwrap();
+        assert!(user.has_role("admin"));
+        assert_eq!(user.roles().len(), 2);
+        assert!(user.modified() > modified);
+
+        let modified = user.modified();
+        user.revoke("support").unwrap();
+        assert!(!user.has_role("support"));
+        assert!(user.modified() >= modified);
+
+        // Ensure that unchanged roles leave the modification time untouched.
+        let modified = user.modified();
+        user.grant("admin").unwrap();
+        user.revoke("support").unwrap();
+        assert_eq!(user.modified(), modified);
+
+        assert_eq!(
+            user.grant("not a role"),
+            Err(Error::Validation(ValidationError::Role))
+        );
+        assert_eq!(user.roles().len(), 1);
+    }
+
+    /// Derives the permissions of test users from a fixed role mapping.
+    impl Authorizable for User<App> {
+        fn can(&self, permission: &str) -> bool {
+            static PERMISSIONS: OnceLock<RolePermissions> = OnceLock::new();
+            PERMISSIONS
+                .get_or_init(|| {
+                    RolePermissions::new()
+                        .with(Role::new("admin").unwrap(), ["users:read", "users:write"])
+                        .with(Role::new("support").unwrap(), ["users:read"])
+                })
+                .allows(self.roles(), permission)
+        }
+    }
+
+    #[test]
+    fn user_authorizable_works() {
+        let mut user = User::<App>::builder()
+            .email("john.doe@example.com")
+            .unwrap()
+            .password("mmholAhsbC123*")
+            .unwrap()
+            .created_now()
+            .modified_now()
+            .build();
+        assert!(!user.can("users:read"));
+
+        user.grant("support").unwrap();
+        assert!(user.can("users:read"));
+        assert!(!user.can("users:write"));
+
+        user.grant("admin").unwrap();
+        assert!(user.can("users:write"));
+        assert!(!user.can("users:delete"));
+    }
+
+    #[test]
+    fn dyn_user_builder_works() {
+        let id = Uuid::new();
+        let created = DateTime::from_unix_secs(1_700_000_000);
+        let user = DynUserBuilder::<App>::new()
+            .id(id)
+            .identity("john.doe@example.com")
+            .password("mmholAhsbC123*")
+            .created(created)
+            .modified_now()
+            .build()
+            .ok()
+            .unwrap();
+        assert_eq!(user.id(), &id);
+        assert_eq!(user.email().as_str(), "john.doe@example.com");
+        assert_eq!(user.created(), created);
+        assert!(user.confirm_password("mmholAhsbC123*").is_ok());
+    }
+
+    #[test]
+    fn dyn_user_builder_reports_all_errors() {
+        let errors = DynUserBuilder::<App>::new()
+            .identity("john.doe")
+            .password("blabla")
+            .modified_now()
+            .build()
+            .err()
+            .unwrap();
+        let errors: Vec<_> = errors
+            .into_iter()
+            .map(|e| match e {
+                Error::Validation(e) => (e.path().unwrap().to_string(), e.kind().clone()),
+                e => panic!("unexpected error {e:?}"),
+            })
+            .collect();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0], ("identity".to_string(), ValidationError::Email));
+        assert_eq!(errors[1].0, "password");
+        assert!(matches!(errors[1].1, ValidationError::Password(_)));
+        assert_eq!(errors[2], ("created".to_string(), ValidationError::Missing));
+    }
 }
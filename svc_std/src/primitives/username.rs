@@ -0,0 +1,165 @@
+use super::error::{Error, ValidationError};
+use crate::traits::validatable::{Validatable, ValidatableAll};
+
+/// Minimum length of a username.
+pub const MIN_LEN: usize = 3;
+
+/// Maximum length of a username.
+pub const MAX_LEN: usize = 32;
+
+/// A validatable username field.
+///
+/// Usernames consist of 3 to 32 ASCII letters, digits, underscores and hyphens
+/// and must start and end with a letter or digit. The case is preserved, use
+/// `Username::canonical` or `Username::eq_ignore_case` for uniqueness checks.
+///
+/// ```rust
+/// # use crate::svc_std::{traits::Validatable, primitives::{Error, Username, ValidationError}};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let username = Username::new("John_Doe")?;
+///     assert!(username.validate().is_ok());
+///     assert!(username.eq_ignore_case(&Username::new("john_doe")?));
+///     assert_eq!(Username::new("-john"), Err(Error::Validation(ValidationError::Username)));
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Username(String);
+
+impl Validatable<Error> for Username {
+    fn validate(&self) -> crate::traits::validatable::Result<Error> {
+        let is_separator = |c: char| c == '_' || c == '-';
+        if !(MIN_LEN..=MAX_LEN).contains(&self.0.len())
+            || !self
+                .0
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || is_separator(c))
+            || self.0.starts_with(is_separator)
+            || self.0.ends_with(is_separator)
+        {
+            return Err(ValidationError::Username.into());
+        }
+        Ok(())
+    }
+}
+
+impl ValidatableAll<Error> for Username {
+    fn validate_all(&self) -> crate::traits::validatable::AllResult<Error> {
+        self.validate().map_err(|e| vec![e])
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Username::new(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Username {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Username {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Username::new(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for Username {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Username {
+    /// Initializes a new username instance.
+    ///
+    /// Returns a validation error if validation of the provided value fails.
+    pub fn new(value: &str) -> Result<Self, Error> {
+        let v = Self(value.to_string());
+        v.validate()?;
+        Ok(v)
+    }
+
+    /// Returns the lowercase form of the username, useful for uniqueness checks.
+    pub fn canonical(&self) -> Username {
+        Self(self.0.to_ascii_lowercase())
+    }
+
+    /// Confirms whether the usernames are equal, ignoring case.
+    pub fn eq_ignore_case(&self, other: &Username) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn username_validation_works() {
+        for value in [
+            "abc",
+            "john_doe",
+            "John-Doe",
+            "j0hn",
+            "a_-b",
+            &"a".repeat(MAX_LEN),
+        ] {
+            assert!(Username::new(value).is_ok(), "{value}");
+        }
+
+        for value in [
+            "",
+            "ab",
+            &"a".repeat(MAX_LEN + 1),
+            "john doe",
+            "john.doe",
+            "jöhn",
+            "_john",
+            "-john",
+            "john_",
+            "john-",
+        ] {
+            assert_eq!(
+                Username::new(value),
+                Err(Error::Validation(ValidationError::Username)),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn username_case_insensitivity_works() {
+        let username = Username::new("John_Doe").unwrap();
+        assert_eq!(username.to_string(), "John_Doe");
+        assert_ne!(username, Username::new("john_doe").unwrap());
+        assert!(username.eq_ignore_case(&Username::new("JOHN_DOE").unwrap()));
+        assert!(!username.eq_ignore_case(&Username::new("jane_doe").unwrap()));
+        assert_eq!(username.canonical(), Username::new("john_doe").unwrap());
+    }
+
+    #[test]
+    fn username_try_from_works() {
+        let username: Username = "john_doe".try_into().unwrap();
+        assert_eq!(username.to_string(), "john_doe");
+        assert!(Username::try_from("j").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn username_serde_works() {
+        let username = Username::new("john_doe").unwrap();
+        let json = serde_json::to_string(&username).unwrap();
+        assert_eq!(json, r#""john_doe""#);
+        assert_eq!(serde_json::from_str::<Username>(&json).unwrap(), username);
+        assert!(serde_json::from_str::<Username>(r#""-john""#).is_err());
+    }
+}
@@ -0,0 +1,272 @@
+//! Module providing in-memory storage for tests and prototypes.
+#[cfg(feature = "memory")]
+use crate::primitives::{
+    user::{Config, User},
+    Email, Error,
+};
+use crate::traits::{Identifiable, Repository};
+use std::{collections::HashMap, convert::Infallible, hash::Hash, sync::Mutex};
+
+/// In-memory implementation of the Repository trait backed by a HashMap.
+///
+/// Entities are cloned on save and get, so the stored entities can't be
+/// mutated without saving them.
+#[derive(Debug)]
+pub struct InMemoryRepository<T: Identifiable> {
+    entities: Mutex<HashMap<T::Id, T>>,
+}
+
+impl<T: Identifiable> Default for InMemoryRepository<T> {
+    fn default() -> Self {
+        Self {
+            entities: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T: Identifiable> InMemoryRepository<T> {
+    /// Initializes a new, empty repository.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of stored entities.
+    pub fn len(&self) -> usize {
+        self.entities
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    /// Confirms whether the repository is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Repository<T, Infallible> for InMemoryRepository<T>
+where
+    T: Identifiable + Clone + Send + Sync,
+    T::Id: Clone + Eq + Hash + Send + Sync,
+{
+    async fn get(&self, id: &T::Id) -> Result<Option<T>, Infallible> {
+        let entities = self.entities.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(entities.get(id).cloned())
+    }
+
+    async fn save(&self, entity: &T) -> Result<(), Infallible> {
+        let mut entities = self.entities.lock().unwrap_or_else(|e| e.into_inner());
+        entities.insert(entity.id().clone(), entity.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: &T::Id) -> Result<(), Infallible> {
+        let mut entities = self.entities.lock().unwrap_or_else(|e| e.into_inner());
+        entities.remove(id);
+        Ok(())
+    }
+}
+
+/// In-memory store for users identified by their email, enforcing unique emails.
+///
+/// Users are indexed by id and by email, saving a user with an email that is
+/// already used by another user returns `Error::DuplicateEmail`.
+#[cfg(feature = "memory")]
+pub struct InMemoryUserStore<T: Config> {
+    users: Mutex<UserIndex<T>>,
+}
+
+#[cfg(feature = "memory")]
+struct UserIndex<T: Config> {
+    by_id: HashMap<T::Id, User<T>>,
+    by_email: HashMap<Email, T::Id>,
+}
+
+#[cfg(feature = "memory")]
+impl<T: Config> Default for InMemoryUserStore<T> {
+    fn default() -> Self {
+        Self {
+            users: Mutex::new(UserIndex {
+                by_id: HashMap::new(),
+                by_email: HashMap::new(),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "memory")]
+impl<T: Config<Identity = Email>> InMemoryUserStore<T>
+where
+    User<T>: Clone,
+    T::Id: Clone + Eq + Hash,
+{
+    /// Initializes a new, empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the user with the provided email, if any.
+    pub async fn get_by_email(&self, email: &Email) -> Result<Option<User<T>>, Error> {
+        let users = self.users.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(users
+            .by_email
+            .get(email)
+            .and_then(|id| users.by_id.get(id))
+            .cloned())
+    }
+}
+
+#[cfg(feature = "memory")]
+impl<T: Config<Identity = Email>> Repository<User<T>, Error> for InMemoryUserStore<T>
+where
+    User<T>: Clone + Send + Sync,
+    T::Id: Clone + Eq + Hash + Send + Sync,
+{
+    async fn get(&self, id: &T::Id) -> Result<Option<User<T>>, Error> {
+        let users = self.users.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(users.by_id.get(id).cloned())
+    }
+
+    async fn save(&self, user: &User<T>) -> Result<(), Error> {
+        let mut users = self.users.lock().unwrap_or_else(|e| e.into_inner());
+        if users
+            .by_email
+            .get(user.email())
+            .is_some_and(|id| id != user.id())
+        {
+            return Err(Error::DuplicateEmail);
+        }
+
+        let previous = users.by_id.insert(user.id().clone(), user.clone());
+        if let Some(previous) = previous {
+            users.by_email.remove(previous.email());
+        }
+        users
+            .by_email
+            .insert(user.email().clone(), user.id().clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: &T::Id) -> Result<(), Error> {
+        let mut users = self.users.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(user) = users.by_id.remove(id) {
+            users.by_email.remove(user.email());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Order {
+        id: u64,
+        total: u32,
+    }
+
+    impl Identifiable for Order {
+        type Id = u64;
+
+        fn id(&self) -> &Self::Id {
+            &self.id
+        }
+    }
+
+    #[tokio::test]
+    async fn in_memory_repository_works() {
+        let repository = InMemoryRepository::new();
+        assert!(repository.is_empty());
+        assert_eq!(repository.get(&1).await, Ok(None));
+
+        let order = Order { id: 1, total: 10 };
+        repository.save(&order).await.unwrap();
+        repository.save(&Order { id: 2, total: 20 }).await.unwrap();
+        assert_eq!(repository.len(), 2);
+        assert_eq!(repository.get(&1).await, Ok(Some(order)));
+
+        // Ensure that saving replaces the entity with the same id.
+        let order = Order { id: 1, total: 15 };
+        repository.save(&order).await.unwrap();
+        assert_eq!(repository.len(), 2);
+        assert_eq!(repository.get(&1).await, Ok(Some(order)));
+
+        repository.delete(&1).await.unwrap();
+        assert_eq!(repository.get(&1).await, Ok(None));
+        assert_eq!(repository.len(), 1);
+
+        // Ensure that deleting a missing entity succeeds.
+        assert_eq!(repository.delete(&1).await, Ok(()));
+    }
+
+    #[cfg(feature = "memory")]
+    mod user_store {
+        use super::super::*;
+        use crate::{
+            password_hasher::argon2::Argon2PasswordHasher,
+            primitives::{DateTime, Email, Uuid},
+        };
+
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        struct App;
+        impl Config for App {
+            type Id = Uuid;
+            type Identity = Email;
+            type PasswordHasher = Argon2PasswordHasher;
+            type DateTime = DateTime;
+        }
+
+        fn user(email: &str) -> User<App> {
+            User::<App>::builder()
+                .id(Uuid::new())
+                .email(email)
+                .unwrap()
+                .password("mmholAhsbC123*")
+                .unwrap()
+                .created_now()
+                .modified_now()
+                .build()
+        }
+
+        #[tokio::test]
+        async fn user_store_lookup_works() {
+            let store = InMemoryUserStore::new();
+            let john = user("john.doe@example.com");
+            store.save(&john).await.unwrap();
+            store.save(&user("jane.doe@example.com")).await.unwrap();
+
+            assert_eq!(store.get(john.id()).await, Ok(Some(john.clone())));
+            assert_eq!(
+                store.get_by_email(john.email()).await,
+                Ok(Some(john.clone()))
+            );
+            assert_eq!(store.get(&Uuid::new()).await, Ok(None));
+
+            store.delete(john.id()).await.unwrap();
+            assert_eq!(store.get(john.id()).await, Ok(None));
+            assert_eq!(store.get_by_email(john.email()).await, Ok(None));
+        }
+
+        #[tokio::test]
+        async fn user_store_email_uniqueness_works() {
+            let store = InMemoryUserStore::new();
+            let mut john = user("john.doe@example.com");
+            store.save(&john).await.unwrap();
+            assert_eq!(
+                store.save(&user("john.doe@example.com")).await,
+                Err(Error::DuplicateEmail)
+            );
+
+            // Ensure that users can keep or change their own email.
+            store.save(&john).await.unwrap();
+            john.update_email("johnny@example.com").unwrap();
+            store.save(&john).await.unwrap();
+            let email = Email::new("john.doe@example.com").unwrap();
+            assert_eq!(store.get_by_email(&email).await, Ok(None));
+
+            // Ensure that the released email can be used again.
+            assert!(store.save(&user("john.doe@example.com")).await.is_ok());
+        }
+    }
+}
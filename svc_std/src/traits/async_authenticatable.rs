@@ -0,0 +1,94 @@
+use super::authenticatable::{Authenticatable, Result};
+use std::future::Future;
+
+/// A trait for password authenticatable objects that need to await I/O,
+/// for example to load data from a database or check rate limits.
+///
+/// Implemented for every `Authenticatable`.
+///
+/// ```rust
+/// # use crate::svc_std::traits::{authenticatable, AsyncAuthenticatable};
+/// struct User {
+///     password: &'static str,
+/// }
+/// impl AsyncAuthenticatable<&'static str> for User {
+///     async fn confirm_password_async(&self, password: &str) -> authenticatable::Result<&'static str> {
+///         // Reminder: Working with litereal passwords is bad!
+///         // Use password hashing in production environments.
+///         if password != self.password { return Err("invalid password") }
+///         Ok(())
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+///     let user = User { password: "testtest" };
+///     assert!(user.confirm_password_async("testtest").await.is_ok());
+/// # }
+/// ```
+pub trait AsyncAuthenticatable<E> {
+    fn confirm_password_async(&self, password: &str) -> impl Future<Output = Result<E>> + Send;
+}
+
+impl<E, T: Authenticatable<E> + Sync> AsyncAuthenticatable<E> for T {
+    async fn confirm_password_async(&self, password: &str) -> Result<E> {
+        self.confirm_password(password)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Account {
+        password: &'static str,
+    }
+
+    impl Authenticatable<&'static str> for Account {
+        fn confirm_password(&self, password: &str) -> Result<&'static str> {
+            if password != self.password {
+                return Err("invalid password");
+            }
+            Ok(())
+        }
+    }
+
+    struct RemoteAccount {
+        password: &'static str,
+    }
+
+    impl AsyncAuthenticatable<&'static str> for RemoteAccount {
+        async fn confirm_password_async(&self, password: &str) -> Result<&'static str> {
+            // Simulate awaiting I/O, like loading the account from a database.
+            tokio::task::yield_now().await;
+            if password != self.password {
+                return Err("invalid password");
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn async_authenticatable_works() {
+        let account = RemoteAccount {
+            password: "testtest",
+        };
+        assert!(account.confirm_password_async("testtest").await.is_ok());
+        assert_eq!(
+            account.confirm_password_async("blabla").await,
+            Err("invalid password")
+        );
+    }
+
+    #[tokio::test]
+    async fn bridged_authenticatable_works() {
+        let account = Account {
+            password: "testtest",
+        };
+        assert!(account.confirm_password_async("testtest").await.is_ok());
+        assert_eq!(
+            account.confirm_password_async("blabla").await,
+            Err("invalid password")
+        );
+    }
+}
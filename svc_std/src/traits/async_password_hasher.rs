@@ -0,0 +1,72 @@
+use super::password_hasher::{Error, PasswordHasher};
+use std::future::Future;
+use zeroize::Zeroizing;
+
+/// A trait for hashing passwords without blocking an async runtime.
+///
+/// Implemented for every `PasswordHasher`, by running the hasher on the tokio
+/// blocking thread pool. Uses distinct function names, so that both traits
+/// can be in scope without ambiguity.
+///
+/// ```rust
+/// # use crate::svc_std::{password_hasher::argon2::Argon2PasswordHasher, traits::AsyncPasswordHasher};
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let hash = Argon2PasswordHasher::hash_async("mmholAhsbC123*").await?;
+///     assert!(Argon2PasswordHasher::confirm_password_async("mmholAhsbC123*", &hash).await.is_ok());
+/// #    Ok(())
+/// # }
+/// ```
+pub trait AsyncPasswordHasher: PasswordHasher + 'static {
+    /// Returns the hash for the provided input or `Error::HashingError` if
+    /// the hashing algorithm failed.
+    fn hash_async(input: &str) -> impl Future<Output = Result<String, Error>> + Send {
+        let input = Zeroizing::new(input.to_string());
+        async move {
+            tokio::task::spawn_blocking(move || Self::hash(&input))
+                .await
+                .map_err(|e| Error::HashingError(e.to_string()))?
+        }
+    }
+
+    /// Confirms whether the provided password matches for the provided hash.
+    ///
+    /// Returns `Error::InvalidPassword` if password validation fails or
+    /// `Error::HashingError` in case of hasher errors.
+    fn confirm_password_async(
+        password: &str,
+        hash: &str,
+    ) -> impl Future<Output = Result<(), Error>> + Send {
+        let password = Zeroizing::new(password.to_string());
+        let hash = hash.to_string();
+        async move {
+            tokio::task::spawn_blocking(move || Self::confirm_password(&password, &hash))
+                .await
+                .map_err(|e| Error::HashingError(e.to_string()))?
+        }
+    }
+}
+
+impl<T: PasswordHasher + 'static> AsyncPasswordHasher for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::password_hasher::argon2::Argon2PasswordHasher;
+
+    #[tokio::test]
+    async fn async_hash_works() {
+        let hash = Argon2PasswordHasher::hash_async("mmholAhsbC123*")
+            .await
+            .unwrap();
+        assert!(
+            Argon2PasswordHasher::confirm_password_async("mmholAhsbC123*", &hash)
+                .await
+                .is_ok()
+        );
+        assert_eq!(
+            Argon2PasswordHasher::confirm_password_async("blabla", &hash).await,
+            Err(Error::InvalidPassword)
+        );
+    }
+}
@@ -0,0 +1,24 @@
+/// A trait for objects whose permissions can be checked, e.g. to gate actions.
+///
+/// Permissions are typically derived from roles, see
+/// `svc_std::primitives::role::RolePermissions`.
+///
+/// ```rust
+/// # use crate::svc_std::traits::Authorizable;
+/// struct ApiClient {
+///     read_only: bool,
+/// }
+/// impl Authorizable for ApiClient {
+///     fn can(&self, permission: &str) -> bool {
+///         permission == "orders:read" || !self.read_only
+///     }
+/// }
+///
+/// let client = ApiClient { read_only: true };
+/// assert!(client.can("orders:read"));
+/// assert!(!client.can("orders:write"));
+/// ```
+pub trait Authorizable {
+    /// Confirms whether the provided permission is granted.
+    fn can(&self, permission: &str) -> bool;
+}
@@ -0,0 +1,37 @@
+/// A trait for objects that record domain events when mutated, e.g. to publish
+/// them through an outbox after persisting the changes.
+///
+/// ```rust
+/// # use crate::svc_std::traits::EventSourced;
+/// enum OrderEvent {
+///     Shipped,
+/// }
+/// struct Order {
+///     shipped: bool,
+///     events: Vec<OrderEvent>,
+/// }
+/// impl Order {
+///     fn ship(&mut self) {
+///         self.shipped = true;
+///         self.events.push(OrderEvent::Shipped);
+///     }
+/// }
+/// impl EventSourced for Order {
+///     type Event = OrderEvent;
+///
+///     fn drain_events(&mut self) -> Vec<OrderEvent> {
+///         std::mem::take(&mut self.events)
+///     }
+/// }
+///
+/// let mut order = Order { shipped: false, events: Vec::new() };
+/// order.ship();
+/// assert_eq!(order.drain_events().len(), 1);
+/// assert!(order.drain_events().is_empty());
+/// ```
+pub trait EventSourced {
+    type Event;
+
+    /// Returns the recorded events in order of occurrence and clears them.
+    fn drain_events(&mut self) -> Vec<Self::Event>;
+}
@@ -0,0 +1,34 @@
+/// A trait for objects with an id.
+///
+/// This trait allows writing generic code over entities, like repositories and indexes.
+///
+/// ```rust
+/// # use crate::svc_std::traits::Identifiable;
+/// # use std::collections::HashMap;
+/// struct Order {
+///     id: u64,
+/// }
+/// impl Identifiable for Order {
+///     type Id = u64;
+///
+///     fn id(&self) -> &Self::Id {
+///         &self.id
+///     }
+/// }
+///
+/// fn index_by_id<T: Identifiable>(items: &[T]) -> HashMap<&T::Id, &T>
+/// where
+///     T::Id: std::hash::Hash + Eq,
+/// {
+///     items.iter().map(|item| (item.id(), item)).collect()
+/// }
+///
+/// let orders = [Order { id: 1 }, Order { id: 2 }];
+/// let index = index_by_id(&orders);
+/// assert_eq!(index[&2].id, 2);
+/// ```
+pub trait Identifiable {
+    type Id;
+
+    fn id(&self) -> &Self::Id;
+}
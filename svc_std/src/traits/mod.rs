@@ -1,9 +1,36 @@
 //! Module providing core traits.
 
+#[cfg(feature = "tokio")]
+pub mod async_authenticatable;
+#[cfg(feature = "tokio")]
+pub mod async_password_hasher;
 pub mod authenticatable;
+pub mod authorizable;
+pub mod event_sourced;
+pub mod identifiable;
 pub mod password_hasher;
+pub mod repository;
+pub mod soft_deletable;
+pub mod timestamped;
+#[cfg(feature = "totp")]
+pub mod totp_authenticatable;
 pub mod validatable;
 
+#[cfg(feature = "tokio")]
+pub use async_authenticatable::AsyncAuthenticatable;
+#[cfg(feature = "tokio")]
+pub use async_password_hasher::AsyncPasswordHasher;
 pub use authenticatable::Authenticatable;
-pub use password_hasher::PasswordHasher;
-pub use validatable::Validatable;
+pub use authorizable::Authorizable;
+pub use event_sourced::EventSourced;
+pub use identifiable::Identifiable;
+pub use password_hasher::{ConfiguredPasswordHasher, DynPasswordHasher, PasswordHasher};
+pub use repository::Repository;
+pub use soft_deletable::SoftDeletable;
+pub use timestamped::Timestamped;
+#[cfg(feature = "totp")]
+pub use totp_authenticatable::TotpAuthenticatable;
+pub use validatable::{Validatable, ValidatableAll};
+
+#[cfg(feature = "derive")]
+pub use svc_std_derive::Validatable;
@@ -6,6 +6,9 @@ pub enum Error {
 
     /// Indicates that password validation failed.
     InvalidPassword,
+
+    /// Indicates that a stored hash couldn't be parsed.
+    MalformedHash,
 }
 
 impl std::fmt::Display for Error {
@@ -13,6 +16,7 @@ impl std::fmt::Display for Error {
         match self {
             Self::HashingError(msg) => write!(f, "hashing failed: {msg}"),
             Self::InvalidPassword => write!(f, "invalid password"),
+            Self::MalformedHash => write!(f, "malformed hash"),
         }
     }
 }
@@ -26,7 +30,49 @@ pub trait PasswordHasher {
 
     /// Confirms whether the provided password matches for the provided hash.
     ///
-    /// Returns `Error::InvalidPassword` if password validation fails or
-    /// `Error::HashingError` in case of hasher errors.
+    /// Returns `Error::InvalidPassword` if password validation fails,
+    /// `Error::MalformedHash` if the stored hash can't be parsed or
+    /// `Error::HashingError` in case of other hasher errors.
+    ///
+    /// Implementations must take a data-independent amount of time with respect
+    /// to the correctness of the password. Early returns may only depend on the
+    /// stored hash, never on the provided password.
     fn confirm_password(password: &str, hash: &str) -> Result<(), Error>;
+
+    /// Confirms whether the provided hash was created with weaker settings
+    /// than the hasher currently targets and should be rehashed.
+    ///
+    /// Returns `Error::MalformedHash` if the hash can't be parsed.
+    fn needs_rehash(hash: &str) -> Result<bool, Error>;
+}
+
+/// A trait for password hasher instances with their own configuration,
+/// like custom cost parameters or a secret key (pepper).
+///
+/// Cost parameters are encoded in the hash, so hashes created without a
+/// secret can still be verified with `PasswordHasher::confirm_password`.
+pub trait ConfiguredPasswordHasher: PasswordHasher {
+    /// Returns the hash for the provided input using the instance's configuration.
+    fn hash_password(&self, input: &str) -> Result<String, Error>;
+
+    /// Confirms whether the provided password matches for the provided hash
+    /// using the instance's configuration.
+    fn verify_password(&self, password: &str, hash: &str) -> Result<(), Error>;
+}
+
+/// An object-safe variant of `PasswordHasher`, e.g. to store a
+/// `Box<dyn DynPasswordHasher>` selected at runtime from configuration.
+///
+/// Static hashers are wrapped with `svc_std::password_hasher::dynamic::DynHasher`.
+pub trait DynPasswordHasher: Send + Sync {
+    /// Returns the hash for the provided input, see `PasswordHasher::hash`.
+    fn hash(&self, input: &str) -> Result<String, Error>;
+
+    /// Confirms whether the provided password matches for the provided hash,
+    /// see `PasswordHasher::confirm_password`.
+    fn confirm_password(&self, password: &str, hash: &str) -> Result<(), Error>;
+
+    /// Confirms whether the provided hash should be rehashed,
+    /// see `PasswordHasher::needs_rehash`.
+    fn needs_rehash(&self, hash: &str) -> Result<bool, Error>;
 }
@@ -0,0 +1,45 @@
+use super::Identifiable;
+use std::future::Future;
+
+/// A trait for persisting identifiable entities.
+///
+/// Decouples services from their storage, `svc_std::store::InMemoryRepository`
+/// provides an implementation for tests and prototypes.
+///
+/// ```rust
+/// # use crate::svc_std::{store::InMemoryRepository, traits::{Identifiable, Repository}};
+/// # use std::convert::Infallible;
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Order {
+///     id: u64,
+/// }
+/// impl Identifiable for Order {
+///     type Id = u64;
+///
+///     fn id(&self) -> &Self::Id {
+///         &self.id
+///     }
+/// }
+///
+/// async fn archive<R: Repository<Order, Infallible>>(repository: &R, id: u64) {
+///     repository.delete(&id).await.unwrap();
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+///     let repository = InMemoryRepository::new();
+///     repository.save(&Order { id: 1 }).await.unwrap();
+///     archive(&repository, 1).await;
+///     assert_eq!(repository.get(&1).await, Ok(None));
+/// # }
+/// ```
+pub trait Repository<T: Identifiable, E> {
+    /// Returns the entity with the provided id, if any.
+    fn get(&self, id: &T::Id) -> impl Future<Output = Result<Option<T>, E>> + Send;
+
+    /// Inserts the entity or replaces the entity with the same id.
+    fn save(&self, entity: &T) -> impl Future<Output = Result<(), E>> + Send;
+
+    /// Deletes the entity with the provided id, if any.
+    fn delete(&self, id: &T::Id) -> impl Future<Output = Result<(), E>> + Send;
+}
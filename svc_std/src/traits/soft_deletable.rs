@@ -0,0 +1,42 @@
+use crate::primitives::DateTime;
+
+/// A trait for objects that are marked as deleted instead of being removed.
+///
+/// ```rust
+/// # use crate::svc_std::{primitives::DateTime, traits::SoftDeletable};
+/// struct Order {
+///     deleted: Option<DateTime>,
+/// }
+/// impl SoftDeletable for Order {
+///     fn deleted_at(&self) -> Option<DateTime> {
+///         self.deleted
+///     }
+///
+///     fn soft_delete(&mut self) {
+///         self.deleted.get_or_insert_with(DateTime::now);
+///     }
+///
+///     fn restore(&mut self) {
+///         self.deleted = None;
+///     }
+/// }
+///
+/// let mut order = Order { deleted: None };
+/// order.soft_delete();
+/// assert!(order.is_deleted());
+/// ```
+pub trait SoftDeletable {
+    /// Returns the time of deletion, if deleted.
+    fn deleted_at(&self) -> Option<DateTime>;
+
+    /// Marks the object as deleted.
+    fn soft_delete(&mut self);
+
+    /// Reverts a deletion.
+    fn restore(&mut self);
+
+    /// Confirms whether the object is marked as deleted.
+    fn is_deleted(&self) -> bool {
+        self.deleted_at().is_some()
+    }
+}
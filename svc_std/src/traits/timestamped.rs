@@ -0,0 +1,74 @@
+use crate::primitives::DateTime;
+
+/// A trait for objects that track their creation and modification time.
+///
+/// Using a concrete `DateTime` allows sorting heterogeneous entities by recency.
+///
+/// ```rust
+/// # use crate::svc_std::{primitives::DateTime, traits::Timestamped};
+/// struct Order {
+///     created: DateTime,
+///     modified: DateTime,
+/// }
+/// impl Timestamped for Order {
+///     fn created(&self) -> DateTime {
+///         self.created
+///     }
+///
+///     fn modified(&self) -> DateTime {
+///         self.modified
+///     }
+/// }
+///
+/// let now = DateTime::now();
+/// let order = Order { created: now, modified: now };
+/// let entities: Vec<&dyn Timestamped> = vec![&order];
+/// assert_eq!(entities[0].modified(), now);
+/// ```
+pub trait Timestamped {
+    fn created(&self) -> DateTime;
+
+    fn modified(&self) -> DateTime;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Post {
+        title: &'static str,
+        modified: DateTime,
+    }
+
+    impl Timestamped for Post {
+        fn created(&self) -> DateTime {
+            DateTime::from_unix_secs(0)
+        }
+
+        fn modified(&self) -> DateTime {
+            self.modified
+        }
+    }
+
+    #[test]
+    fn sort_by_modified_works() {
+        let mut posts = [
+            Post {
+                title: "b",
+                modified: DateTime::from_unix_secs(1_700_000_060),
+            },
+            Post {
+                title: "c",
+                modified: DateTime::from_unix_secs(1_700_000_120),
+            },
+            Post {
+                title: "a",
+                modified: DateTime::from_unix_secs(1_700_000_000),
+            },
+        ];
+
+        posts.sort_by_key(|post| std::cmp::Reverse(post.modified()));
+        let titles: Vec<_> = posts.iter().map(|post| post.title).collect();
+        assert_eq!(titles, ["c", "b", "a"]);
+    }
+}
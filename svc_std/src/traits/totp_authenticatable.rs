@@ -0,0 +1,19 @@
+use super::authenticatable::Result;
+
+/// A trait for objects that can be authenticated with a time-based one-time password.
+///
+/// This trait complements `Authenticatable` as a second factor,
+/// for example as `svc_std::primitives::totp::Totp` does.
+///
+/// ```rust
+/// # use crate::svc_std::{primitives::{Error, Totp}, traits::TotpAuthenticatable};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let totp = Totp::new("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ")?;
+///     assert!(totp.confirm_totp(&totp.generate()).is_ok());
+///     assert_eq!(totp.confirm_totp("abcdef"), Err(Error::InvalidTotpCode));
+/// #    Ok(())
+/// # }
+/// ```
+pub trait TotpAuthenticatable<E> {
+    fn confirm_totp(&self, code: &str) -> Result<E>;
+}
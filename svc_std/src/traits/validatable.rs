@@ -27,3 +27,185 @@ pub type Result<E> = core::result::Result<(), E>;
 pub trait Validatable<E> {
     fn validate(&self) -> Result<E>;
 }
+
+/// Validates the value if present, absent values are valid.
+impl<E, T: Validatable<E>> Validatable<E> for Option<T> {
+    fn validate(&self) -> Result<E> {
+        match self {
+            Some(value) => value.validate(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validates every element, returning the first error.
+impl<E, T: Validatable<E>> Validatable<E> for Vec<T> {
+    fn validate(&self) -> Result<E> {
+        self.iter().try_for_each(Validatable::validate)
+    }
+}
+
+/// A trait for validation errors that can carry the path of the failed field.
+///
+/// Nested objects prefix the path of their fields' errors with their own field name.
+///
+/// ```rust
+/// # use crate::svc_std::{primitives::{Email, Error, ValidationError}, traits::validatable::{self, FieldContext, Validatable}};
+/// struct Person {
+///     email: Email,
+/// }
+/// impl Validatable<Error> for Person {
+///     fn validate(&self) -> validatable::Result<Error> {
+///         self.email.validate().map_err(|e| e.at("email"))
+///     }
+/// }
+///
+/// let error = Error::from(ValidationError::Email).at("email").at("owner");
+/// let Error::Validation(error) = error else { unreachable!() };
+/// assert_eq!(error.path(), Some("owner.email"));
+/// assert_eq!(error.kind(), &ValidationError::Email);
+/// ```
+pub trait FieldContext {
+    /// Adds the provided field name in front of the error's field path.
+    fn at(self, field: &str) -> Self;
+}
+
+/// Type alias for validation results reporting all errors.
+///
+/// Requires only a generic type for errors.
+pub type AllResult<E> = core::result::Result<(), Vec<E>>;
+
+/// A trait for objects or fields that report all validation errors at once.
+///
+/// ```rust
+/// # use crate::svc_std::traits::validatable::{self, ValidatableAll};
+/// pub struct Form {
+///     name: String,
+///     email: String,
+/// }
+/// impl ValidatableAll<String> for Form {
+///     fn validate_all(&self) -> validatable::AllResult<String> {
+///         let name: validatable::AllResult<String> = match self.name.is_empty() {
+///             true => Err(vec!["error: empty name".to_string()]),
+///             false => Ok(()),
+///         };
+///         let email: validatable::AllResult<String> = match self.email.contains('@') {
+///             true => Ok(()),
+///             false => Err(vec!["error: invalid email address".to_string()]),
+///         };
+///         validatable::combine([name, email])
+///     }
+/// }
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let form = Form { name: "".to_string(), email: "invalid email".to_string() };
+///     assert_eq!(form.validate_all().unwrap_err().len(), 2);
+///     Ok(())
+/// }
+/// ```
+pub trait ValidatableAll<E> {
+    fn validate_all(&self) -> AllResult<E>;
+}
+
+/// Combines the provided validation results, merging all their errors.
+pub fn combine<E>(results: impl IntoIterator<Item = AllResult<E>>) -> AllResult<E> {
+    let errors: Vec<E> = results
+        .into_iter()
+        .filter_map(|result| result.err())
+        .flatten()
+        .collect();
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok(())
+}
+
+/// Accumulates the validation results of independent fields, e.g. in a handler,
+/// keeping the name of every failed field.
+///
+/// Nothing short-circuits, `finish` reports every error in the order of the fields.
+///
+/// ```rust
+/// # use crate::svc_std::{primitives::{Email, Error, Uuid}, traits::validatable::Validator};
+/// let result = Validator::new()
+///     .field("email", Email::new("john.doe"))
+///     .field("id", Uuid::try_from("123"))
+///     .finish();
+/// let fields: Vec<_> = result.unwrap_err().into_iter().map(|(field, _)| field).collect();
+/// assert_eq!(fields, ["email", "id"]);
+/// ```
+#[derive(Debug)]
+pub struct Validator<E> {
+    errors: Vec<(String, E)>,
+}
+
+impl<E> Default for Validator<E> {
+    fn default() -> Self {
+        Self { errors: Vec::new() }
+    }
+}
+
+impl<E> Validator<E> {
+    /// Initializes a new validator without errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the error of the provided result, if any, for the named field.
+    pub fn field<T>(mut self, name: &str, result: core::result::Result<T, E>) -> Self {
+        if let Err(e) = result {
+            self.errors.push((name.to_string(), e));
+        }
+        self
+    }
+
+    /// Validates the provided value and records its error, if any, for the named field.
+    pub fn validate(self, name: &str, value: &impl Validatable<E>) -> Self {
+        self.field(name, value.validate())
+    }
+
+    /// Returns all recorded errors with their field names.
+    pub fn finish(self) -> core::result::Result<(), Vec<(String, E)>> {
+        if !self.errors.is_empty() {
+            return Err(self.errors);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Name(&'static str);
+    impl Validatable<String> for Name {
+        fn validate(&self) -> Result<String> {
+            match self.0.is_empty() {
+                true => Err("empty".to_string()),
+                false => Ok(()),
+            }
+        }
+    }
+
+    #[test]
+    fn validator_collects_all_errors() {
+        let result = Validator::new()
+            .field("email", Err::<(), _>("invalid email".to_string()))
+            .validate("first_name", &Name("John"))
+            .field("age", Ok::<_, String>(42))
+            .validate("last_name", &Name(""))
+            .finish();
+        assert_eq!(
+            result,
+            Err(vec![
+                ("email".to_string(), "invalid email".to_string()),
+                ("last_name".to_string(), "empty".to_string()),
+            ])
+        );
+
+        let result = Validator::new()
+            .validate("first_name", &Name("John"))
+            .finish();
+        assert_eq!(result, Ok(()));
+    }
+}
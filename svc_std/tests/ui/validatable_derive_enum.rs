@@ -0,0 +1,8 @@
+use svc_std::traits::Validatable;
+
+#[derive(Validatable)]
+enum Contact {
+    Anonymous,
+}
+
+fn main() {}
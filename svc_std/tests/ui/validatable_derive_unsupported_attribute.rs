@@ -0,0 +1,9 @@
+use svc_std::{primitives::Email, traits::Validatable};
+
+#[derive(Validatable)]
+struct Contact {
+    #[validate(ignore)]
+    email: Email,
+}
+
+fn main() {}
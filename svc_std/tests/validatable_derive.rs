@@ -0,0 +1,88 @@
+#![cfg(feature = "derive")]
+
+use svc_std::{
+    primitives::{Email, Error, Uuid, ValidationError},
+    traits::Validatable,
+};
+
+#[derive(Validatable)]
+struct Contact {
+    id: Uuid,
+    email: Email,
+    #[validate(skip)]
+    #[allow(dead_code)]
+    name: String,
+}
+
+struct Unverified;
+
+impl Validatable<Error> for Unverified {
+    fn validate(&self) -> svc_std::traits::validatable::Result<Error> {
+        Err(ValidationError::Email.into())
+    }
+}
+
+#[derive(Validatable)]
+struct Person {
+    id: Uuid,
+    email: Unverified,
+}
+
+#[derive(Validatable)]
+struct Team {
+    owner: Person,
+}
+
+#[derive(Validatable)]
+#[validate(error = String)]
+struct Wrapper(Name);
+
+struct Name(&'static str);
+
+impl Validatable<String> for Name {
+    fn validate(&self) -> svc_std::traits::validatable::Result<String> {
+        if self.0.is_empty() {
+            return Err("error: empty name".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn derive_validatable_works() {
+    let contact = Contact {
+        id: Uuid::new(),
+        email: Email::new("john.doe@example.com").unwrap(),
+        name: String::new(),
+    };
+    assert!(contact.validate().is_ok());
+
+    assert!(Wrapper(Name("John")).validate().is_ok());
+    assert_eq!(
+        Wrapper(Name("")).validate(),
+        Err("error: empty name".to_string())
+    );
+}
+
+#[test]
+fn derive_validatable_reports_path() {
+    let team = Team {
+        owner: Person {
+            id: Uuid::new(),
+            email: Unverified,
+        },
+    };
+    assert_eq!(
+        team.validate(),
+        Err(Error::Validation(ValidationError::Field {
+            path: "owner.email".to_string(),
+            kind: Box::new(ValidationError::Email),
+        }))
+    );
+}
+
+#[test]
+fn derive_validatable_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
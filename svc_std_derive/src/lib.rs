@@ -0,0 +1,92 @@
+//! Derive macros for the standard library for building services.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Index, Member, Type};
+
+/// Derives `Validatable` by validating every field, short-circuiting on the first error.
+///
+/// The error type defaults to `svc_std::primitives::Error` and can be set with
+/// `#[validate(error = MyError)]` on the struct. Fields marked with
+/// `#[validate(skip)]` aren't validated, all other fields must implement
+/// `Validatable` for the error type.
+///
+/// With the default error type, field errors are annotated with the field's
+/// path through `FieldContext`, so nested objects report e.g. `owner.email`.
+#[proc_macro_derive(Validatable, attributes(validate))]
+pub fn derive_validatable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_validatable(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_validatable(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut error: Type = syn::parse_quote!(::svc_std::primitives::Error);
+    let mut custom_error = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("error") {
+                error = meta.value()?.parse()?;
+                custom_error = true;
+                return Ok(());
+            }
+            Err(meta.error("unsupported validate attribute"))
+        })?;
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Validatable can only be derived for structs",
+        ));
+    };
+
+    let mut checks = Vec::new();
+    for (i, field) in data.fields.iter().enumerate() {
+        let mut skip = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("validate")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skip = true;
+                    return Ok(());
+                }
+                Err(meta.error("unsupported validate attribute"))
+            })?;
+        }
+        if skip {
+            continue;
+        }
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+        let check = quote! {
+            ::svc_std::traits::Validatable::<#error>::validate(&self.#member)
+        };
+        checks.push(match custom_error {
+            true => quote! { #check?; },
+            false => {
+                let path = match &member {
+                    Member::Named(ident) => ident.to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                };
+                quote! {
+                    #check.map_err(|e| ::svc_std::traits::validatable::FieldContext::at(e, #path))?;
+                }
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::svc_std::traits::Validatable<#error> for #ident #ty_generics #where_clause {
+            fn validate(&self) -> ::svc_std::traits::validatable::Result<#error> {
+                #(#checks)*
+                Ok(())
+            }
+        }
+    })
+}